@@ -0,0 +1,106 @@
+//! Discovers the puzzle modules in `src/yYYYY/day_DD/mod.rs` and generates the module
+//! declarations and the puzzle registry, so adding a day only requires adding its module.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+struct DayModule {
+    module: String,
+    year: u16,
+    day: u8,
+}
+
+fn main() {
+    let src_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let mut years: BTreeMap<(u16, String), Vec<DayModule>> = BTreeMap::new();
+    for year_dir in sorted_dirs(&src_dir) {
+        let year_module = file_name(&year_dir);
+        let Some(year) = parse_number::<u16>(&year_module, "y") else {
+            continue;
+        };
+        for day_dir in sorted_dirs(&year_dir) {
+            let module = file_name(&day_dir);
+            let Some(day) = parse_number::<u8>(&module, "day_") else {
+                continue;
+            };
+            if day_dir.join("mod.rs").is_file() {
+                years
+                    .entry((year, year_module.clone()))
+                    .or_default()
+                    .push(DayModule { module, year, day });
+            }
+        }
+    }
+
+    let mut modules = String::new();
+    let mut registry = String::from("&[\n");
+    let mut registered: BTreeMap<(u16, u8), String> = BTreeMap::new();
+
+    for ((_, year_module), mut days) in years {
+        days.sort_by_key(|d| d.day);
+        let year_path = src_dir.join(&year_module);
+        writeln!(modules, "#[path = {:?}]", year_path.display().to_string()).unwrap();
+        writeln!(modules, "pub mod {} {{", year_module).unwrap();
+
+        for day in days {
+            let path = format!("{}::{}", year_module, day.module);
+            if let Some(existing) = registered.insert((day.year, day.day), path.clone()) {
+                writeln!(
+                    modules,
+                    "    compile_error!(\"duplicate puzzle registration for {} day {}: {} and {}\");",
+                    day.year, day.day, existing, path
+                )
+                .unwrap();
+                continue;
+            }
+            writeln!(modules, "    pub mod {};", day.module).unwrap();
+            writeln!(
+                registry,
+                "    ({}, {}, crate::{}::puzzle),",
+                day.year, day.day, path
+            )
+            .unwrap();
+        }
+        writeln!(modules, "}}").unwrap();
+    }
+    registry.push(']');
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    write_if_changed(&out_dir.join("puzzle_modules.rs"), &modules);
+    write_if_changed(&out_dir.join("puzzle_registry.rs"), &registry);
+}
+
+fn sorted_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {}", dir.display(), err))
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().unwrap().to_string_lossy().into_owned()
+}
+
+fn parse_number<T: std::str::FromStr>(name: &str, prefix: &str) -> Option<T> {
+    let digits = name.strip_prefix(prefix)?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+// Rewriting identical content would needlessly trigger a rebuild of the crate
+fn write_if_changed(path: &Path, content: &str) {
+    if fs::read_to_string(path).ok().as_deref() != Some(content) {
+        fs::write(path, content)
+            .unwrap_or_else(|err| panic!("Failed to write {}: {}", path.display(), err));
+    }
+}
@@ -0,0 +1,149 @@
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// The answer to a part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    /// An integer that doesn't fit in an `i64`.
+    BigInt(i128),
+    Text(String),
+    /// A picture drawn by the solution, such as letters made of marked grid cells, together
+    /// with the text it reads as. The text is the answer to submit and compare against.
+    Art {
+        picture: String,
+        text: String,
+    },
+}
+
+impl Answer {
+    pub fn art(picture: impl Into<String>, text: impl Into<String>) -> Self {
+        Answer::Art {
+            picture: picture.into(),
+            text: text.into(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Answer::Int(_) | Answer::BigInt(_) => false,
+            Answer::Text(text) | Answer::Art { text, .. } => text.is_empty(),
+        }
+    }
+
+    /// The picture of an `Art` answer, one entry per line.
+    pub fn picture_lines(&self) -> Vec<&str> {
+        match self {
+            Answer::Art { picture, .. } => picture.lines().collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Whether this is the expected answer. Integers are compared by value, so that e.g. an
+    /// expected `+42` or `042` still matches.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Int(n) => expected.trim().parse::<i128>() == Ok(i128::from(*n)),
+            Answer::BigInt(n) => expected.trim().parse::<i128>() == Ok(*n),
+            Answer::Text(text) | Answer::Art { text, .. } => text == expected,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Text(text) | Answer::Art { text, .. } => write!(f, "{}", text),
+        }
+    }
+}
+
+// Numbers serialize as numbers and text as strings. Art keeps its lines, so it can be shown
+// without splitting the string again.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Art<'a> {
+            text: &'a str,
+            picture: Vec<&'a str>,
+        }
+
+        match self {
+            Answer::Int(n) => serializer.serialize_i64(*n),
+            Answer::BigInt(n) => serializer.serialize_i128(*n),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Art { text, .. } => Art {
+                text,
+                picture: self.picture_lines(),
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match i64::try_from(n) {
+                    Ok(n) => Answer::Int(n),
+                    Err(_) => Answer::BigInt(n as i128),
+                }
+            }
+        })*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_pick_the_smallest_variant() {
+        assert_eq!(Answer::from(42usize), Answer::Int(42));
+        assert_eq!(Answer::from(-7i32), Answer::Int(-7));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(Answer::from(i128::MIN), Answer::BigInt(i128::MIN));
+    }
+
+    #[test]
+    fn compare_with_expected() {
+        assert!(Answer::Int(42).matches("42"));
+        assert!(Answer::Int(42).matches("042"));
+        assert!(!Answer::Int(42).matches("43"));
+        assert!(Answer::BigInt(u64::MAX as i128 + 1).matches("18446744073709551616"));
+        assert!(Answer::from("LKREBPRK").matches("LKREBPRK"));
+        assert!(!Answer::from("042").matches("42"));
+        assert!(Answer::art("#..#\n#..#", "H").matches("H"));
+    }
+
+    #[test]
+    fn serialize_answers() {
+        let answers = vec![
+            Answer::Int(7),
+            Answer::BigInt(i128::from(i64::MAX) + 1),
+            Answer::from("abc"),
+            Answer::art("#.\n.#", "X"),
+        ];
+        assert_eq!(
+            serde_json::to_string(&answers).unwrap(),
+            r##"[7,9223372036854775808,"abc",{"text":"X","picture":["#.",".#"]}]"##
+        );
+    }
+}
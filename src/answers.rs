@@ -0,0 +1,361 @@
+use crate::aoc_puzzle::{AocPuzzle, PartResult, PuzzlePart};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail => "FAIL",
+            Verdict::Missing => "MISSING",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, Serialize)]
+pub struct VerificationCounts {
+    pub pass: usize,
+    pub fail: usize,
+    pub missing: usize,
+}
+
+impl VerificationCounts {
+    pub fn add(&mut self, verdict: Verdict) {
+        match verdict {
+            Verdict::Pass => self.pass += 1,
+            Verdict::Fail => self.fail += 1,
+            Verdict::Missing => self.missing += 1,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AnswerValue {
+    Text(String),
+    Number(i64),
+}
+
+impl AnswerValue {
+    fn into_string(self) -> String {
+        match self {
+            AnswerValue::Text(text) => text,
+            AnswerValue::Number(number) => number.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DayAnswers {
+    silver: Option<AnswerValue>,
+    gold: Option<AnswerValue>,
+}
+
+/// Known answers for a set of puzzle inputs, keyed by year and day.
+///
+/// The file format is TOML with one table per day:
+///
+/// ```toml
+/// [2025.1]
+/// silver = "999"
+/// gold = "6099"
+/// ```
+#[derive(Default)]
+pub struct ExpectedAnswers {
+    days: HashMap<(u16, u8), [Option<String>; 2]>,
+}
+
+impl ExpectedAnswers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read answers file {}: {}", path.display(), err))?;
+        Self::parse(&content)
+            .map_err(|err| format!("Invalid answers file {}: {}", path.display(), err))
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let years: HashMap<String, HashMap<String, DayAnswers>> =
+            toml::from_str(content).map_err(|err| err.to_string())?;
+
+        let mut expected = Self::default();
+        for (year_key, year_days) in years {
+            let year: u16 = year_key
+                .parse()
+                .map_err(|_| format!("invalid year key '{}'", year_key))?;
+            for (day_key, answers) in year_days {
+                let day: u8 = day_key
+                    .parse()
+                    .map_err(|_| format!("invalid day key '{}.{}'", year_key, day_key))?;
+                expected.insert(year, day, answers);
+            }
+        }
+        Ok(expected)
+    }
+
+    /// Loads the answers of the puzzle examples, which have one table per example and are
+    /// returned keyed by example number:
+    ///
+    /// ```toml
+    /// [2025.1.1]
+    /// silver = "3"
+    /// gold = "6"
+    /// ```
+    pub fn load_examples(path: &Path) -> Result<HashMap<u32, Self>, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read answers file {}: {}", path.display(), err))?;
+        Self::parse_examples(&content)
+            .map_err(|err| format!("Invalid answers file {}: {}", path.display(), err))
+    }
+
+    fn parse_examples(content: &str) -> Result<HashMap<u32, Self>, String> {
+        let years: HashMap<String, HashMap<String, HashMap<String, DayAnswers>>> =
+            toml::from_str(content).map_err(|err| err.to_string())?;
+
+        let mut examples: HashMap<u32, Self> = HashMap::new();
+        for (year_key, year_days) in years {
+            let year: u16 = year_key
+                .parse()
+                .map_err(|_| format!("invalid year key '{}'", year_key))?;
+            for (day_key, day_examples) in year_days {
+                let day: u8 = day_key
+                    .parse()
+                    .map_err(|_| format!("invalid day key '{}.{}'", year_key, day_key))?;
+                for (number_key, answers) in day_examples {
+                    let number: u32 = number_key.parse().map_err(|_| {
+                        format!(
+                            "invalid example key '{}.{}.{}'",
+                            year_key, day_key, number_key
+                        )
+                    })?;
+                    examples
+                        .entry(number)
+                        .or_default()
+                        .insert(year, day, answers);
+                }
+            }
+        }
+        Ok(examples)
+    }
+
+    fn insert(&mut self, year: u16, day: u8, answers: DayAnswers) {
+        self.days.insert(
+            (year, day),
+            [
+                answers.silver.map(AnswerValue::into_string),
+                answers.gold.map(AnswerValue::into_string),
+            ],
+        );
+    }
+
+    pub fn expected(&self, year: u16, day: u8, part: PuzzlePart) -> Option<Vec<String>> {
+        let [silver, gold] = self.days.get(&(year, day))?;
+        match part {
+            PuzzlePart::Silver => Some(vec![silver.clone()?]),
+            PuzzlePart::Gold => Some(vec![gold.clone()?]),
+            PuzzlePart::Both => Some(vec![silver.clone()?, gold.clone()?]),
+        }
+    }
+
+    /// Compares a computed result against the expected answers and records the verdict on it.
+    /// Skipped parts and dry runs have nothing to compare and are left without a verdict, but a
+    /// part that timed out or failed without an answer doesn't match one that is expected.
+    pub fn verify(&self, result: &mut PartResult) -> Option<Verdict> {
+        let failed =
+            result.timeout_s.is_some() || result.error.is_some() || result.puzzle_error.is_some();
+        if result.skipped.is_some() || (result.answers.is_empty() && !failed) {
+            return None;
+        }
+
+        let verdict = match self.expected(result.year, result.day, result.part) {
+            Some(expected) => compare(result, expected),
+            None => Verdict::Missing,
+        };
+        result.verdict = Some(verdict);
+        Some(verdict)
+    }
+}
+
+/// Checks that the puzzles with a dedicated `both` solution agree with their own silver and
+/// gold solutions. Both parts of an input are reported before `both`, so their answers are
+/// kept until the `both` result of the same input comes in and is verified against them.
+pub struct BothCheck {
+    dedicated: HashSet<(u16, u8)>,
+    parts: HashMap<(u16, u8, Option<String>), [Option<String>; 2]>,
+}
+
+impl BothCheck {
+    pub fn new<'a>(puzzles: impl IntoIterator<Item = &'a AocPuzzle>) -> Self {
+        Self {
+            dedicated: puzzles
+                .into_iter()
+                .filter(|puzzle| puzzle.has_both())
+                .map(|puzzle| (puzzle.year, puzzle.day))
+                .collect(),
+            parts: HashMap::new(),
+        }
+    }
+
+    /// Records the answer of a silver or gold result, or verifies a `both` result against them.
+    /// Only `both` results get a verdict, which is missing when a part has no answer to compare.
+    pub fn verify(&mut self, result: &mut PartResult) -> Option<Verdict> {
+        if result.skipped.is_some()
+            || result.answers.is_empty()
+            || !self.dedicated.contains(&(result.year, result.day))
+        {
+            return None;
+        }
+
+        let key = (result.year, result.day, result.input.clone());
+        let index = match result.part {
+            PuzzlePart::Silver => 0,
+            PuzzlePart::Gold => 1,
+            PuzzlePart::Both => {
+                let verdict = match self.parts.remove(&key).unwrap_or_default() {
+                    [Some(silver), Some(gold)] => compare(result, vec![silver, gold]),
+                    _ => Verdict::Missing,
+                };
+                result.verdict = Some(verdict);
+                return Some(verdict);
+            }
+        };
+        self.parts.entry(key).or_default()[index] = Some(result.answers[0].to_string());
+        None
+    }
+}
+
+/// Compares the answers of a result, and records what was expected when they don't match.
+fn compare(result: &mut PartResult, expected: Vec<String>) -> Verdict {
+    if expected.len() == result.answers.len()
+        && result
+            .answers
+            .iter()
+            .zip(&expected)
+            .all(|(answer, expected)| answer.matches(expected))
+    {
+        Verdict::Pass
+    } else {
+        result.expected = Some(expected);
+        Verdict::Fail
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    const ANSWERS: &str =
+        "[2025.1]\nsilver = \"999\"\ngold = 6099\n\n[2021.13]\nsilver = \"653\"\n";
+
+    #[test]
+    fn parse_answers() {
+        let answers = ExpectedAnswers::parse(ANSWERS).unwrap();
+        assert_eq!(
+            answers.expected(2025, 1, PuzzlePart::Both),
+            Some(vec!["999".to_string(), "6099".to_string()])
+        );
+        assert_eq!(
+            answers.expected(2021, 13, PuzzlePart::Silver),
+            Some(vec!["653".to_string()])
+        );
+        assert_eq!(answers.expected(2021, 13, PuzzlePart::Gold), None);
+        assert_eq!(answers.expected(2021, 13, PuzzlePart::Both), None);
+        assert_eq!(answers.expected(2015, 1, PuzzlePart::Silver), None);
+    }
+
+    #[test]
+    fn verify_result() {
+        let answers = ExpectedAnswers::parse(ANSWERS).unwrap();
+        let mut result = PartResult::new(2025, 1, PuzzlePart::Both);
+        result.answers = vec![Answer::Int(999), Answer::Int(6099)];
+        assert_eq!(answers.verify(&mut result), Some(Verdict::Pass));
+
+        result.answers[1] = Answer::from("6099 ");
+        assert_eq!(answers.verify(&mut result), Some(Verdict::Fail));
+        assert_eq!(
+            result.expected,
+            Some(vec!["999".to_string(), "6099".to_string()])
+        );
+
+        // A part that ran out of time has no answer, which is not the expected one
+        let mut timed_out = PartResult::new(2025, 1, PuzzlePart::Gold);
+        timed_out.timeout_s = Some(10.0);
+        assert_eq!(answers.verify(&mut timed_out), Some(Verdict::Fail));
+        timed_out.year = 2015;
+        assert_eq!(answers.verify(&mut timed_out), Some(Verdict::Missing));
+
+        // Dry runs have no answers to compare
+        let mut dry_run = PartResult::new(2025, 1, PuzzlePart::Gold);
+        assert_eq!(answers.verify(&mut dry_run), None);
+    }
+
+    #[test]
+    fn check_both_against_parts() {
+        // The dedicated `both` gets gold wrong on any input but an empty one
+        let dedicated = AocPuzzle::new(2025, 1, |_| Ok(Answer::Int(1)), |_| Ok(Answer::Int(2)))
+            .with_both(|input| Ok((Answer::Int(1), Answer::Int(2 + input.len() as i64))));
+        let separate = AocPuzzle::new(2025, 2, |_| Ok(Answer::Int(1)), |_| Ok(Answer::Int(2)));
+        let mut check = BothCheck::new([&dedicated, &separate]);
+
+        let mut verdicts = Vec::new();
+        for (puzzle, name, input) in [
+            (&dedicated, None, ""),
+            (&dedicated, Some("alice"), "x"),
+            (&separate, None, "x"),
+        ] {
+            for part in [PuzzlePart::Silver, PuzzlePart::Gold, PuzzlePart::Both] {
+                let mut result = PartResult::new(puzzle.year, puzzle.day, part);
+                result.input = name.map(String::from);
+                result.answers = puzzle.run_part(part, input).unwrap();
+                if let Some(verdict) = check.verify(&mut result) {
+                    verdicts.push((name, verdict, result.expected));
+                }
+            }
+        }
+        let expected = vec!["1".to_string(), "2".to_string()];
+        assert_eq!(
+            verdicts,
+            vec![
+                (None, Verdict::Pass, None),
+                (Some("alice"), Verdict::Fail, Some(expected))
+            ]
+        );
+
+        // Without the silver and gold answers, there is nothing to check against
+        let mut both = PartResult::new(2025, 1, PuzzlePart::Both);
+        both.answers = vec![Answer::Int(1), Answer::Int(2)];
+        assert_eq!(check.verify(&mut both), Some(Verdict::Missing));
+    }
+
+    #[test]
+    fn parse_invalid_key() {
+        assert!(ExpectedAnswers::parse("[year.1]\nsilver = \"1\"\n").is_err());
+        assert!(ExpectedAnswers::parse_examples("[2025.1.first]\nsilver = \"1\"\n").is_err());
+    }
+
+    #[test]
+    fn parse_example_answers() {
+        let examples =
+            ExpectedAnswers::parse_examples("[2025.11.1]\nsilver = 5\n\n[2025.11.2]\ngold = 2\n")
+                .unwrap();
+        assert_eq!(
+            examples[&1].expected(2025, 11, PuzzlePart::Silver),
+            Some(vec!["5".to_string()])
+        );
+        assert_eq!(examples[&1].expected(2025, 11, PuzzlePart::Gold), None);
+        assert_eq!(
+            examples[&2].expected(2025, 11, PuzzlePart::Gold),
+            Some(vec!["2".to_string()])
+        );
+    }
+}
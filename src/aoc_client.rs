@@ -0,0 +1,596 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_CONFIG_PATH: &str = "./aoc.toml";
+
+const SESSION_ENV: &str = "AOC_SESSION";
+const BASE_URL_ENV: &str = "AOC_BASE_URL";
+const USER_AGENT: &str = "advent-of-rust (https://github.com/RuneBjonness/advent-of-rust)";
+
+/// The optional configuration file. Both keys can also be given through the environment.
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    session: Option<String>,
+    base_url: Option<String>,
+}
+
+/// How to reach the Advent of Code server.
+pub struct ClientConfig {
+    pub base_url: String,
+    pub session: String,
+    /// Minimum time between two requests to the server.
+    pub interval: Duration,
+}
+
+impl ClientConfig {
+    /// Takes the session token from `AOC_SESSION` or the config file, and the base URL from
+    /// `base_url`, `AOC_BASE_URL`, the config file or the default, in that order.
+    /// A missing config file is not an error, as long as the session token is found elsewhere.
+    pub fn load(
+        config_path: &str,
+        base_url: Option<String>,
+        interval: Duration,
+    ) -> Result<Self, String> {
+        let file = match fs::read_to_string(config_path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|err| format!("Invalid config file {}: {}", config_path, err))?,
+            Err(_) => ConfigFile::default(),
+        };
+        Self::resolve(
+            file,
+            env::var(SESSION_ENV).ok(),
+            base_url.or_else(|| env::var(BASE_URL_ENV).ok()),
+            interval,
+        )
+        .map_err(|err| format!("{} (config file: {})", err, config_path))
+    }
+
+    fn resolve(
+        file: ConfigFile,
+        session: Option<String>,
+        base_url: Option<String>,
+        interval: Duration,
+    ) -> Result<Self, String> {
+        let session = session
+            .or(file.session)
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| {
+                format!(
+                    "No session token, set {} or add `session = \"...\"` to the config file",
+                    SESSION_ENV
+                )
+            })?;
+        let base_url = base_url
+            .or(file.base_url)
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+            .trim_end_matches('/')
+            .to_string();
+        Ok(Self {
+            base_url,
+            session,
+            interval,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CacheOutcome {
+    Cached,
+    Downloaded,
+}
+
+/// The server's verdict on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmitOutcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Another answer was submitted too recently, so this one wasn't checked
+    RateLimited,
+    /// The part is already solved, or gold was submitted before silver
+    WrongLevel,
+}
+
+impl SubmitOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubmitOutcome::Right => "right",
+            SubmitOutcome::Wrong => "wrong",
+            SubmitOutcome::TooHigh => "too high",
+            SubmitOutcome::TooLow => "too low",
+            SubmitOutcome::RateLimited => "rate limited",
+            SubmitOutcome::WrongLevel => "wrong level",
+        }
+    }
+
+    /// Whether the server rejected the answer, so that submitting it again is pointless.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            SubmitOutcome::Wrong | SubmitOutcome::TooHigh | SubmitOutcome::TooLow
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubmitResponse {
+    pub outcome: SubmitOutcome,
+    /// How long the server asks to wait before submitting another answer
+    pub wait: Option<Duration>,
+}
+
+/// How long a request, including reading its response, may take.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Talks to an Advent of Code compatible server, spacing requests out by the configured interval.
+pub struct AocClient {
+    config: ClientConfig,
+    agent: ureq::Agent,
+    last_request: Option<Instant>,
+}
+
+impl AocClient {
+    pub fn new(config: ClientConfig) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(REQUEST_TIMEOUT))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        Self {
+            config,
+            agent,
+            last_request: None,
+        }
+    }
+
+    /// Downloads the input to `path`, unless the file already has content. An empty file, as
+    /// created by the `new` command, counts as missing.
+    pub fn cache_input(&mut self, year: u16, day: u8, path: &Path) -> Result<CacheOutcome, String> {
+        if has_content(path) {
+            return Ok(CacheOutcome::Cached);
+        }
+
+        let input = self.fetch_input(year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("Failed to create {}: {}", dir.display(), err))?;
+        }
+        // Write to a temporary file first, so an interrupted write never leaves a partial input
+        let partial = path.with_extension("part");
+        fs::write(&partial, input)
+            .and_then(|_| fs::rename(&partial, path))
+            .map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+        Ok(CacheOutcome::Downloaded)
+    }
+
+    pub fn fetch_input(&mut self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/input", self.config.base_url, year, day);
+        self.wait_for_turn();
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.config.session))
+            .call();
+        match read_response(response).map_err(|err| request_error(&url, err))? {
+            (200, body) => Ok(body),
+            (status, body) => Err(self.status_error(year, day, &url, status, &body)),
+        }
+    }
+
+    /// Posts the answer to a part, level 1 for silver and 2 for gold, and reads the verdict from
+    /// the page that comes back.
+    pub fn submit_answer(
+        &mut self,
+        year: u16,
+        day: u8,
+        level: u8,
+        answer: &str,
+    ) -> Result<SubmitResponse, String> {
+        let url = format!("{}/{}/day/{}/answer", self.config.base_url, year, day);
+        self.wait_for_turn();
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.config.session))
+            .send_form([("level", level.to_string().as_str()), ("answer", answer)]);
+        match read_response(response).map_err(|err| submit_error(&url, err))? {
+            (200, body) => parse_submit_response(&body).ok_or_else(|| {
+                format!(
+                    "Unrecognized response from {}: {}",
+                    url,
+                    article_text(&body)
+                )
+            }),
+            (status, body) => Err(self.status_error(year, day, &url, status, &body)),
+        }
+    }
+
+    fn status_error(&self, year: u16, day: u8, url: &str, status: u16, body: &str) -> String {
+        match status {
+            404 => format!("{} day {} is not available yet ({})", year, day, url),
+            400 | 401 | 403 => format!(
+                "The session token was rejected by {}, it may have expired",
+                self.config.base_url
+            ),
+            _ => format!(
+                "Unexpected response from {}: HTTP {}: {}",
+                url,
+                status,
+                body.trim()
+            ),
+        }
+    }
+
+    fn wait_for_turn(&mut self) {
+        if let Some(last) = self.last_request {
+            if let Some(remaining) = self.config.interval.checked_sub(last.elapsed()) {
+                thread::sleep(remaining);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+}
+
+pub fn has_content(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.len() > 0)
+}
+
+fn read_response(
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<(u16, String), ureq::Error> {
+    let mut response = response?;
+    let body = response.body_mut().read_to_string()?;
+    Ok((response.status().as_u16(), body))
+}
+
+fn request_error(url: &str, err: ureq::Error) -> String {
+    match err {
+        ureq::Error::Timeout(_) => format!(
+            "No response from {} within {} s",
+            url,
+            REQUEST_TIMEOUT.as_secs()
+        ),
+        err => format!("Request to {} failed: {}", url, err),
+    }
+}
+
+/// A submission that timed out may still have been received, and submitting it again could
+/// count as another wrong answer, so the message says to check first.
+fn submit_error(url: &str, err: ureq::Error) -> String {
+    match err {
+        ureq::Error::Timeout(_) => format!(
+            "{}, the answer may still have been received: check the puzzle page before \
+             submitting it again",
+            request_error(url, err)
+        ),
+        err => request_error(url, err),
+    }
+}
+
+/// Reads the verdict from the text of the page, which is all the server gives to go by.
+fn parse_submit_response(body: &str) -> Option<SubmitResponse> {
+    let text = article_text(body);
+    let outcome = if text.contains("That's the right answer") {
+        SubmitOutcome::Right
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            SubmitOutcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            SubmitOutcome::TooLow
+        } else {
+            SubmitOutcome::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        SubmitOutcome::RateLimited
+    } else if text.contains("You don't seem to be solving the right level") {
+        SubmitOutcome::WrongLevel
+    } else {
+        return None;
+    };
+    Some(SubmitResponse {
+        outcome,
+        wait: parse_wait(&text),
+    })
+}
+
+// "You have 1m 5s left to wait" after a rate-limited answer, "Please wait one minute before
+// trying again" or "please wait 5 minutes before trying again" after a wrong one
+fn parse_wait(text: &str) -> Option<Duration> {
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    if let Some(captures) = left.captures(text) {
+        let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = captures[2].parse().unwrap();
+        return Some(Duration::from_secs(minutes * 60 + seconds));
+    }
+    let minutes = Regex::new(r"(?i)wait (one|\d+) minutes? before trying again").unwrap();
+    let count = minutes.captures(text)?;
+    let count: u64 = match &count[1] {
+        "one" => 1,
+        n => n.parse().unwrap(),
+    };
+    Some(Duration::from_secs(count * 60))
+}
+
+/// The text of the `<article>` with the message on a page, or of the whole page without one.
+fn article_text(body: &str) -> String {
+    let article = match (body.find("<article"), body.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &body[start..end],
+        _ => body,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+
+    /// A stand-in server that answers each request with the next of `responses`, and reports
+    /// the request line and headers it received, followed by the body if there is one.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, Receiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request: Vec<String> = reader
+                    .by_ref()
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                let length = request.iter().find_map(|header| {
+                    let (name, value) = header.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().unwrap())
+                });
+                if let Some(length) = length {
+                    let mut content = vec![0; length];
+                    reader.read_exact(&mut content).unwrap();
+                    request.push(String::from_utf8(content).unwrap());
+                }
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                sender.send(request).unwrap();
+            }
+        });
+        (base_url, receiver)
+    }
+
+    fn client(base_url: String, interval: Duration) -> AocClient {
+        AocClient::new(ClientConfig {
+            base_url,
+            session: "secret".to_string(),
+            interval,
+        })
+    }
+
+    #[test]
+    fn resolve_config() {
+        let file = ConfigFile {
+            session: Some("from-file".to_string()),
+            base_url: Some("http://file/".to_string()),
+        };
+        let config = ClientConfig::resolve(file, None, None, Duration::ZERO).unwrap();
+        assert_eq!(config.session, "from-file");
+        assert_eq!(config.base_url, "http://file");
+
+        let config = ClientConfig::resolve(
+            ConfigFile::default(),
+            Some("from-env\n".to_string()),
+            None,
+            Duration::ZERO,
+        )
+        .unwrap();
+        assert_eq!(config.session, "from-env");
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+
+        assert!(ClientConfig::resolve(ConfigFile::default(), None, None, Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn fetch_input_sends_the_session() {
+        let (base_url, requests) = mock_server(vec![(200, "1\n2\n3\n")]);
+        let mut client = client(base_url, Duration::ZERO);
+
+        assert_eq!(client.fetch_input(2025, 1).unwrap(), "1\n2\n3\n");
+        let request = requests.recv().unwrap();
+        assert_eq!(request[0], "GET /2025/day/1/input HTTP/1.1");
+        assert!(request
+            .iter()
+            .any(|header| header.eq_ignore_ascii_case("cookie: session=secret")));
+    }
+
+    #[test]
+    fn fetch_input_errors() {
+        let (base_url, _requests) = mock_server(vec![
+            (404, "Not Found"),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+        ]);
+        let mut client = client(base_url, Duration::ZERO);
+
+        assert!(client
+            .fetch_input(2030, 1)
+            .unwrap_err()
+            .contains("not available yet"));
+        assert!(client
+            .fetch_input(2025, 1)
+            .unwrap_err()
+            .contains("session token was rejected"));
+    }
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn parse_submit_responses() {
+        let outcome = |message: &str| parse_submit_response(&page(message)).unwrap();
+
+        assert_eq!(
+            outcome("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."),
+            SubmitResponse {
+                outcome: SubmitOutcome::Right,
+                wait: None
+            }
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too high.  If you're stuck, [...] Please wait one minute before trying again. <a href=\"/2025/day/1\">[Return to Day 1]</a>"),
+            SubmitResponse {
+                outcome: SubmitOutcome::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.").wait,
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            outcome("That's not the right answer.  If you're stuck, make sure you're using the full input data.").outcome,
+            SubmitOutcome::Wrong
+        );
+        assert_eq!(
+            outcome("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."),
+            SubmitResponse {
+                outcome: SubmitOutcome::RateLimited,
+                wait: Some(Duration::from_secs(65))
+            }
+        );
+        assert_eq!(
+            outcome("You gave an answer too recently.  You have 34s left to wait.").wait,
+            Some(Duration::from_secs(34))
+        );
+        assert_eq!(
+            outcome("You don't seem to be solving the right level.  Did you already complete it?")
+                .outcome,
+            SubmitOutcome::WrongLevel
+        );
+        assert_eq!(
+            parse_submit_response(&page("Something else entirely")),
+            None
+        );
+    }
+
+    #[test]
+    fn submit_answer_posts_the_level_and_answer() {
+        let (base_url, requests) = mock_server(vec![
+            (200, "<article><p>That's the right answer!</p></article>"),
+            (200, "<article><p>Welcome to the server</p></article>"),
+        ]);
+        let mut client = client(base_url, Duration::ZERO);
+
+        let response = client.submit_answer(2025, 1, 2, "6099").unwrap();
+        assert_eq!(response.outcome, SubmitOutcome::Right);
+        let request = requests.recv().unwrap();
+        assert_eq!(request[0], "POST /2025/day/1/answer HTTP/1.1");
+        assert!(request
+            .iter()
+            .any(|header| header.eq_ignore_ascii_case("cookie: session=secret")));
+        assert_eq!(request.last().unwrap(), "level=2&answer=6099");
+
+        assert!(client
+            .submit_answer(2025, 1, 2, "6099")
+            .unwrap_err()
+            .contains("Unrecognized response"));
+    }
+
+    #[test]
+    fn timeouts_are_explained() {
+        let url = "https://adventofcode.com/2025/day/1/answer";
+        assert_eq!(
+            submit_error(url, ureq::Error::Timeout(ureq::Timeout::Global)),
+            "No response from https://adventofcode.com/2025/day/1/answer within 30 s, the answer \
+             may still have been received: check the puzzle page before submitting it again"
+        );
+        assert_eq!(
+            submit_error(url, ureq::Error::HostNotFound),
+            "Request to https://adventofcode.com/2025/day/1/answer failed: host not found"
+        );
+    }
+
+    #[test]
+    fn requests_are_rate_limited() {
+        let (base_url, _requests) = mock_server(vec![(200, "a"), (200, "b")]);
+        let mut client = client(base_url, Duration::from_millis(200));
+
+        let start = Instant::now();
+        client.fetch_input(2025, 1).unwrap();
+        client.fetch_input(2025, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn existing_inputs_are_not_downloaded_again() {
+        let dir = env::temp_dir().join(format!("aoc_client_test_{}", std::process::id()));
+        let cached = dir.join("2025_01.txt");
+        let placeholder = dir.join("2025_02.txt");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&cached, "cached").unwrap();
+        fs::write(&placeholder, "").unwrap();
+
+        // Only one response: a second download would fail to connect
+        let (base_url, requests) = mock_server(vec![(200, "downloaded")]);
+        let mut client = client(base_url, Duration::ZERO);
+
+        assert_eq!(
+            client.cache_input(2025, 1, &cached),
+            Ok(CacheOutcome::Cached)
+        );
+        assert_eq!(
+            client.cache_input(2025, 2, &placeholder),
+            Ok(CacheOutcome::Downloaded)
+        );
+        assert_eq!(
+            client.cache_input(2025, 2, &placeholder),
+            Ok(CacheOutcome::Cached)
+        );
+        assert_eq!(
+            requests.recv().unwrap()[0],
+            "GET /2025/day/2/input HTTP/1.1"
+        );
+        assert_eq!(fs::read_to_string(&cached).unwrap(), "cached");
+        assert_eq!(fs::read_to_string(&placeholder).unwrap(), "downloaded");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,8 +1,22 @@
-use std::fmt::Display;
-use std::fs;
-use std::time::Instant;
+use crate::answer::Answer;
+use crate::answers::Verdict;
+use crate::baseline::BaselineDelta;
+use crate::memory::{self, MemoryStats};
+use crate::panics::catch_solution_panic;
+use crate::puzzle_error::PuzzleError;
+use crate::stats::TimingStats;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
 pub enum PuzzlePart {
     Silver = 0,
     Gold = 1,
@@ -10,7 +24,7 @@ pub enum PuzzlePart {
 }
 
 impl PuzzlePart {
-    fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &'static str {
         match self {
             PuzzlePart::Silver => "silver",
             PuzzlePart::Gold => "gold",
@@ -19,34 +33,233 @@ impl PuzzlePart {
     }
 }
 
-pub type SilverFn = fn(&str) -> Box<dyn Display>;
-pub type GoldFn = fn(&str) -> Box<dyn Display>;
-pub type BothFn = fn(&str) -> (Box<dyn Display>, Box<dyn Display>);
+/// The outcome of solving a single part of a puzzle.
+///
+/// `answers` holds one value for silver and gold, and two (silver, gold) for both.
+/// It is empty when the part was skipped, failed, timed out or not actually computed (dry run).
+/// `error` is set when the solution panicked or the input couldn't be read, and `puzzle_error`
+/// when the solution itself returned an error.
+#[derive(Clone, Serialize)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: PuzzlePart,
+    /// The input set the part was solved with, when it isn't the default one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+    pub answers: Vec<Answer>,
+    pub duration_ms: f64,
+    /// How long the parse stage took, for puzzles that have one. It is set on the first part
+    /// solved from an input, since the others share what it parsed, and isn't in `duration_ms`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_ms: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<TimingStats>,
+    /// Heap use of the last run, with the `alloc-stats` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_s: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub puzzle_error: Option<PuzzleError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<Verdict>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<BaselineDelta>,
+}
+
+impl PartResult {
+    pub fn new(year: u16, day: u8, part: PuzzlePart) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            input: None,
+            answers: Vec::new(),
+            duration_ms: 0.0,
+            parse_ms: None,
+            timing: None,
+            memory: None,
+            skipped: None,
+            timeout_s: None,
+            error: None,
+            puzzle_error: None,
+            verdict: None,
+            expected: None,
+            baseline: None,
+        }
+    }
+}
+
+/// Controls how a part is executed by `AocPuzzle::solve_part`.
+#[derive(Clone, Copy)]
+pub struct SolveOptions {
+    pub dry_run: bool,
+    /// Untimed runs before measuring, to warm up caches and the allocator.
+    pub warmup: u32,
+    /// Number of timed runs. With more than one, timing statistics are collected.
+    pub iterations: u32,
+    /// Maximum duration of a single run, overriding the puzzle's own timeout.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for SolveOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            warmup: 0,
+            iterations: 1,
+            timeout: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum RunError {
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+pub type PuzzleResult<T = Answer> = Result<T, PuzzleError>;
+
+pub type SilverFn = fn(&str) -> PuzzleResult;
+pub type GoldFn = fn(&str) -> PuzzleResult;
+pub type BothFn = fn(&str) -> PuzzleResult<(Answer, Answer)>;
+
+type Measurement = (PuzzleResult<Vec<Answer>>, f64, Option<MemoryStats>);
+
+/// An input as the parse stage of a puzzle returned it, shared by the parts solved from it.
+type ParsedInput = Arc<dyn Any + Send + Sync>;
+
+/// The parse stage of a puzzle and the silver and gold solutions of what it parses, with the
+/// type of the parsed input erased so that every puzzle fits in the same collection.
+trait Stages: Send + Sync {
+    fn parse(&self, input: &str) -> PuzzleResult<ParsedInput>;
+    fn silver(&self, parsed: &ParsedInput) -> PuzzleResult;
+    fn gold(&self, parsed: &ParsedInput) -> PuzzleResult;
+}
+
+struct TypedStages<T> {
+    parse: fn(&str) -> PuzzleResult<T>,
+    silver: fn(&T) -> PuzzleResult,
+    gold: fn(&T) -> PuzzleResult,
+}
+
+impl<T: 'static> TypedStages<T> {
+    fn input(parsed: &ParsedInput) -> &T {
+        parsed
+            .downcast_ref()
+            .expect("input parsed by another puzzle")
+    }
+}
+
+impl<T: Send + Sync + 'static> Stages for TypedStages<T> {
+    fn parse(&self, input: &str) -> PuzzleResult<ParsedInput> {
+        Ok(Arc::new((self.parse)(input)?))
+    }
+
+    fn silver(&self, parsed: &ParsedInput) -> PuzzleResult {
+        (self.silver)(Self::input(parsed))
+    }
+
+    fn gold(&self, parsed: &ParsedInput) -> PuzzleResult {
+        (self.gold)(Self::input(parsed))
+    }
+}
+
+type ParsedBothFn = dyn Fn(&ParsedInput) -> PuzzleResult<(Answer, Answer)> + Send + Sync;
+
+/// A dedicated `both` solution, taking the input text or the input parsed by the parse stage.
+#[derive(Clone)]
+enum Both {
+    Text(BothFn),
+    Parsed(Arc<ParsedBothFn>),
+}
+
+/// How the silver and gold solutions of a puzzle take their input.
+#[derive(Clone)]
+enum Solution {
+    Text { silver: SilverFn, gold: GoldFn },
+    Parsed(Arc<dyn Stages>),
+}
+
+/// What a run of a part takes: the input text, and for a puzzle with a parse stage, the input
+/// parsed from it.
+#[derive(Clone)]
+struct PartInput {
+    text: Arc<str>,
+    parsed: Option<ParsedInput>,
+}
 
 #[derive(Clone)]
 pub struct AocPuzzle {
     pub year: u16,
     pub day: u8,
-    silver: SilverFn,
-    gold: GoldFn,
-    both: Option<BothFn>,
+    solution: Solution,
+    both: Option<Both>,
     skip_parts: [Option<&'static str>; 3],
+    /// Skipped parts that run after all when `--timeout` bounds how long they may take
+    slow_parts: [bool; 3],
+    /// Parts that are still stubs, see `AocPuzzle::unsolved`
+    unsolved_parts: [bool; 3],
+    timeout: Option<Duration>,
 }
 
 impl AocPuzzle {
     pub fn new(year: u16, day: u8, silver: SilverFn, gold: GoldFn) -> Self {
+        Self::with_solution(year, day, Solution::Text { silver, gold })
+    }
+
+    fn with_solution(year: u16, day: u8, solution: Solution) -> Self {
         Self {
             year,
             day,
-            silver,
-            gold,
+            solution,
             both: None,
             skip_parts: [None; 3],
+            slow_parts: [false; 3],
+            unsolved_parts: [false; 3],
+            timeout: None,
         }
     }
 
+    /// A puzzle that parses its input once, in a stage timed on its own, and hands the parsed
+    /// input to silver and gold. Solving several parts from the same input only parses it once.
+    pub fn parsed<T: Send + Sync + 'static>(
+        year: u16,
+        day: u8,
+        parse: fn(&str) -> PuzzleResult<T>,
+        silver: fn(&T) -> PuzzleResult,
+        gold: fn(&T) -> PuzzleResult,
+    ) -> Self {
+        let stages = TypedStages {
+            parse,
+            silver,
+            gold,
+        };
+        Self::with_solution(year, day, Solution::Parsed(Arc::new(stages)))
+    }
+
     pub fn with_both(mut self, both: BothFn) -> Self {
-        self.both = Some(both);
+        self.both = Some(Both::Text(both));
+        self
+    }
+
+    /// A dedicated `both` solution for a puzzle made with `AocPuzzle::parsed`, which takes the
+    /// same parsed input as silver and gold.
+    pub fn with_parsed_both<T: Send + Sync + 'static>(
+        mut self,
+        both: fn(&T) -> PuzzleResult<(Answer, Answer)>,
+    ) -> Self {
+        assert!(self.has_parse(), "a parsed both needs a parse stage");
+        let both = move |parsed: &ParsedInput| both(TypedStages::input(parsed));
+        self.both = Some(Both::Parsed(Arc::new(both)));
         self
     }
 
@@ -55,79 +268,395 @@ impl AocPuzzle {
         self
     }
 
-    fn default_input_path(&self) -> String {
-        format!("./input/{}_{:02}.txt", self.year, self.day)
+    /// Default time limit for each run of a part, for puzzles that are too slow to always wait for.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
     }
 
-    pub fn read_input(&self, path: Option<&str>) -> String {
-        let default_path = self.default_input_path();
-        let path = path.unwrap_or(&default_path);
-        fs::read_to_string(path)
-            .unwrap_or_else(|_| panic!("Failed to read input file: {}", path))
-            .trim_end()
-            .to_string()
+    /// Skips a part that is too slow to run by default, unless a timeout is given for the run,
+    /// so that it can't keep a thread busy for minutes without being asked to.
+    pub fn skip_unless_timeout(mut self, part: PuzzlePart, reason: &'static str) -> Self {
+        self.slow_parts[part as usize] = true;
+        self.skip(part, reason)
     }
 
-    pub fn solve_part(&self, part: PuzzlePart, input: &str, dry_run: bool) -> f64 {
-        let mut result_value: String;
-        let mut duration_ms = 0.0;
+    /// Marks a part that still returns `PuzzleError::NotImplemented`, as the days created by
+    /// `new` do, so that `list` doesn't count the puzzle as done. It still runs as usual.
+    pub fn unsolved(mut self, part: PuzzlePart) -> Self {
+        self.unsolved_parts[part as usize] = true;
+        self
+    }
 
-        if let Some(skip_reason) = self.skip_parts[part as usize] {
-            result_value = skip_reason.to_string();
-        } else {
-            let start = Instant::now();
-
-            if dry_run {
-                result_value = String::new();
-            } else {
-                match part {
-                    PuzzlePart::Silver => {
-                        result_value = (self.silver)(input).to_string();
-                    }
-                    PuzzlePart::Gold => {
-                        result_value = (self.gold)(input).to_string();
-                    }
-                    PuzzlePart::Both => {
-                        let (silver_result, gold_result) = if let Some(both) = self.both {
-                            both(input)
-                        } else {
-                            ((self.silver)(input), (self.gold)(input))
-                        };
-                        result_value = format!("{}\n{:>35}{}", silver_result, "", gold_result);
+    /// Whether every part has a solution, rather than a stub marked with `unsolved`.
+    pub fn is_solved(&self) -> bool {
+        !self.unsolved_parts.contains(&true)
+    }
+
+    pub fn skip_reason(&self, part: PuzzlePart) -> Option<&'static str> {
+        self.skip_parts[part as usize]
+    }
+
+    /// Why a part is skipped in a run with the given options, if it is.
+    pub fn run_skip_reason(
+        &self,
+        part: PuzzlePart,
+        options: &SolveOptions,
+    ) -> Option<&'static str> {
+        if options.timeout.is_some() && self.slow_parts[part as usize] {
+            return None;
+        }
+        self.skip_reason(part)
+    }
+
+    /// Whether `both` has its own solution, rather than running silver and gold one after another.
+    pub fn has_both(&self) -> bool {
+        self.both.is_some()
+    }
+
+    /// Whether the puzzle parses its input in a stage of its own, see `AocPuzzle::parsed`.
+    pub fn has_parse(&self) -> bool {
+        matches!(self.solution, Solution::Parsed(_))
+    }
+
+    pub fn solve_part(&self, part: PuzzlePart, input: &str, options: &SolveOptions) -> PartResult {
+        self.solver(input, options).solve(part)
+    }
+
+    /// Prepares to solve parts from one input. A puzzle with a parse stage parses it when the
+    /// first part that isn't skipped is solved, and the other parts reuse what it parsed.
+    pub fn solver(&self, input: &str, options: &SolveOptions) -> InputSolver<'_> {
+        InputSolver {
+            puzzle: self,
+            options: *options,
+            input: PartInput {
+                text: Arc::from(input),
+                parsed: None,
+            },
+            parse_failure: None,
+        }
+    }
+
+    fn measure(&self, part: PuzzlePart, input: &PartInput) -> Result<Measurement, RunError> {
+        time_run(|| self.run_on(part, input))
+    }
+
+    fn measure_with_timeout(
+        &self,
+        part: PuzzlePart,
+        input: &PartInput,
+        runs: u32,
+        timeout: Duration,
+    ) -> Result<Vec<Measurement>, RunError> {
+        let puzzle = self.clone();
+        let input = input.clone();
+        run_with_timeout(runs, timeout, move || puzzle.measure(part, &input))
+    }
+
+    /// Runs a part once, without timing it or checking whether it should be skipped.
+    pub fn run_part(&self, part: PuzzlePart, input: &str) -> PuzzleResult<Vec<Answer>> {
+        let parsed = match &self.solution {
+            Solution::Text { .. } => None,
+            Solution::Parsed(stages) => Some(stages.parse(input)?),
+        };
+        let input = PartInput {
+            text: Arc::from(input),
+            parsed,
+        };
+        self.run_on(part, &input)
+    }
+
+    fn run_on(&self, part: PuzzlePart, input: &PartInput) -> PuzzleResult<Vec<Answer>> {
+        let solve = |part| match (&self.solution, &input.parsed) {
+            (Solution::Text { silver, .. }, _) if part == PuzzlePart::Silver => silver(&input.text),
+            (Solution::Text { gold, .. }, _) => gold(&input.text),
+            (Solution::Parsed(stages), Some(parsed)) if part == PuzzlePart::Silver => {
+                stages.silver(parsed)
+            }
+            (Solution::Parsed(stages), Some(parsed)) => stages.gold(parsed),
+            (Solution::Parsed(_), None) => unreachable!("part solved before parsing its input"),
+        };
+        match part {
+            PuzzlePart::Silver => Ok(vec![solve(PuzzlePart::Silver)?]),
+            PuzzlePart::Gold => Ok(vec![solve(PuzzlePart::Gold)?]),
+            PuzzlePart::Both => {
+                let (silver_result, gold_result) = match (&self.both, &input.parsed) {
+                    (Some(Both::Text(both)), _) => both(&input.text)?,
+                    (Some(Both::Parsed(both)), Some(parsed)) => both(parsed)?,
+                    (Some(Both::Parsed(_)), None) => {
+                        unreachable!("part solved before parsing its input")
                     }
-                }
+                    (None, _) => (solve(PuzzlePart::Silver)?, solve(PuzzlePart::Gold)?),
+                };
+                Ok(vec![silver_result, gold_result])
             }
+        }
+    }
+}
 
-            duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+/// Why the parse stage of a puzzle failed on an input, which every part solved from it reports.
+#[derive(Clone)]
+enum ParseFailure {
+    Run(RunError),
+    Puzzle(PuzzleError),
+}
 
-            if result_value.is_empty() {
-                result_value = "Not solved".to_string();
+/// Solves the parts of a puzzle from one input, see `AocPuzzle::solver`.
+pub struct InputSolver<'a> {
+    puzzle: &'a AocPuzzle,
+    options: SolveOptions,
+    input: PartInput,
+    parse_failure: Option<ParseFailure>,
+}
+
+impl InputSolver<'_> {
+    pub fn solve(&mut self, part: PuzzlePart) -> PartResult {
+        let puzzle = self.puzzle;
+        let options = self.options;
+        let mut result = PartResult::new(puzzle.year, puzzle.day, part);
+        result.skipped = puzzle.run_skip_reason(part, &options);
+
+        if result.skipped.is_some() {
+            return result;
+        }
+
+        // Time what a run costs around the solution, with one that does nothing
+        if options.dry_run {
+            if let Ok((_, duration_ms, _)) = time_run(|| Ok(Vec::new())) {
+                result.duration_ms = duration_ms;
+            }
+            return result;
+        }
+
+        match self.parse(&mut result) {
+            Ok(()) => {}
+            Err(ParseFailure::Run(err)) => {
+                record_run_error(&mut result, err);
+                return result;
+            }
+            Err(ParseFailure::Puzzle(err)) => {
+                result.puzzle_error = Some(err);
+                return result;
             }
         }
 
-        let duration_str = if duration_ms > 0.0 {
-            format!("{:.1} ms", duration_ms)
+        let iterations = options.iterations.max(1);
+        let runs = options.warmup + iterations;
+        let measurements = match options.timeout.or(puzzle.timeout) {
+            Some(timeout) => puzzle.measure_with_timeout(part, &self.input, runs, timeout),
+            None => (0..runs)
+                .map(|_| puzzle.measure(part, &self.input))
+                .collect(),
+        };
+
+        let measurements = match measurements {
+            Ok(measurements) => measurements,
+            Err(err) => {
+                record_run_error(&mut result, err);
+                return result;
+            }
+        };
+
+        let mut samples = Vec::with_capacity(iterations as usize);
+        for (answers, duration_ms, memory) in measurements.into_iter().skip(options.warmup as usize)
+        {
+            match answers {
+                Ok(answers) => result.answers = answers,
+                Err(err) => result.puzzle_error = Some(err),
+            }
+            samples.push(duration_ms);
+            result.memory = memory;
+        }
+
+        if iterations > 1 {
+            let stats = TimingStats::from_samples(&samples);
+            result.duration_ms = stats.median_ms;
+            result.timing = Some(stats);
         } else {
-            "--".to_string()
+            result.duration_ms = samples[0];
+        }
+        result
+    }
+
+    /// Parses the input for the first part solved from it, once, under the same time limit as
+    /// a run of a part. The time it took goes on the result of that part.
+    fn parse(&mut self, result: &mut PartResult) -> Result<(), ParseFailure> {
+        let Solution::Parsed(stages) = &self.puzzle.solution else {
+            return Ok(());
         };
+        if let Some(failure) = &self.parse_failure {
+            return Err(failure.clone());
+        }
+        if self.input.parsed.is_some() {
+            return Ok(());
+        }
+
+        let parse = {
+            let stages = Arc::clone(stages);
+            let text = Arc::clone(&self.input.text);
+            move || {
+                let start = Instant::now();
+                let parsed =
+                    catch_solution_panic(|| stages.parse(&text)).map_err(RunError::Panicked)?;
+                Ok((parsed, start.elapsed().as_secs_f64() * 1000.0))
+            }
+        };
+        let outcome = match self.options.timeout.or(self.puzzle.timeout) {
+            Some(timeout) => run_with_timeout(1, timeout, parse).map(|mut runs| runs.remove(0)),
+            None => parse(),
+        };
+
+        let failure = match outcome {
+            Ok((Ok(parsed), parse_ms)) => {
+                result.parse_ms = Some(parse_ms);
+                self.input.parsed = Some(parsed);
+                return Ok(());
+            }
+            Ok((Err(err), parse_ms)) => {
+                result.parse_ms = Some(parse_ms);
+                ParseFailure::Puzzle(err)
+            }
+            Err(err) => ParseFailure::Run(err),
+        };
+        self.parse_failure = Some(failure.clone());
+        Err(failure)
+    }
+}
+
+/// Runs a part once, catching a panic, and measures how long it took and what it allocated.
+fn time_run(run: impl FnOnce() -> PuzzleResult<Vec<Answer>>) -> Result<Measurement, RunError> {
+    let ((answers, duration_ms), memory) = memory::measure(|| {
+        let start = Instant::now();
+        let answers = catch_solution_panic(run);
+        (answers, start.elapsed().as_secs_f64() * 1000.0)
+    });
+    Ok((answers.map_err(RunError::Panicked)?, duration_ms, memory))
+}
+
+fn record_run_error(result: &mut PartResult, err: RunError) {
+    match err {
+        RunError::Panicked(message) => result.error = Some(message),
+        RunError::TimedOut(timeout) => result.timeout_s = Some(timeout.as_secs_f64()),
+    }
+}
+
+/// Calls `run` on a worker thread `runs` times, giving up if any single run exceeds the
+/// timeout. A run that timed out cannot be interrupted, so it keeps going in the background
+/// until it finishes or the process exits.
+fn run_with_timeout<T: Send + 'static>(
+    runs: u32,
+    timeout: Duration,
+    run: impl Fn() -> Result<T, RunError> + Send + 'static,
+) -> Result<Vec<T>, RunError> {
+    let (sender, receiver) = mpsc::channel();
 
-        println!(
-            "{} | {:>2} | {:<7} | {:>10} | {}",
-            self.year,
-            self.day,
-            part.as_str(),
-            duration_str,
-            result_value
+    thread::spawn(move || {
+        for _ in 0..runs {
+            let outcome = run();
+            let failed = outcome.is_err();
+            if sender.send(outcome).is_err() || failed {
+                break;
+            }
+        }
+    });
+
+    let mut outcomes = Vec::with_capacity(runs as usize);
+    for _ in 0..runs {
+        match receiver.recv_timeout(timeout) {
+            Ok(outcome) => outcomes.push(outcome?),
+            Err(RecvTimeoutError::Timeout) => return Err(RunError::TimedOut(timeout)),
+            Err(RecvTimeoutError::Disconnected) => {
+                unreachable!("worker finished without sending all runs")
+            }
+        }
+    }
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    fn parse_numbers(input: &str) -> PuzzleResult<Vec<i64>> {
+        PARSES.fetch_add(1, Ordering::Relaxed);
+        input
+            .split(',')
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| PuzzleError::parse_at(input, value, "invalid number"))
+            })
+            .collect()
+    }
+
+    fn puzzle() -> AocPuzzle {
+        AocPuzzle::parsed(
+            2025,
+            1,
+            parse_numbers,
+            |numbers: &Vec<i64>| Ok(Answer::Int(numbers.iter().sum())),
+            |numbers| Ok(Answer::Int(numbers.iter().product())),
+        )
+    }
+
+    #[test]
+    fn parts_share_the_parsed_input() {
+        let puzzle = puzzle();
+        assert!(puzzle.has_parse());
+        assert_eq!(
+            puzzle.run_part(PuzzlePart::Both, "2,3,4").unwrap(),
+            vec![Answer::Int(9), Answer::Int(24)]
         );
 
-        duration_ms
+        let parses = PARSES.load(Ordering::Relaxed);
+        let mut solver = puzzle.solver("2,3,4", &SolveOptions::default());
+        let results: Vec<PartResult> = [PuzzlePart::Silver, PuzzlePart::Gold, PuzzlePart::Both]
+            .into_iter()
+            .map(|part| solver.solve(part))
+            .collect();
+        assert_eq!(PARSES.load(Ordering::Relaxed), parses + 1);
+        assert_eq!(results[2].answers, vec![Answer::Int(9), Answer::Int(24)]);
+
+        // Only the part that parsed the input reports how long that took
+        assert!(results[0].parse_ms.is_some());
+        assert!(results[1].parse_ms.is_none() && results[2].parse_ms.is_none());
     }
 
-    pub fn solve(&self, input: &str, dry_run: bool) -> f64 {
-        let mut duration_ms = 0.0;
-        duration_ms += self.solve_part(PuzzlePart::Silver, input, dry_run);
-        duration_ms += self.solve_part(PuzzlePart::Gold, input, dry_run);
-        duration_ms += self.solve_part(PuzzlePart::Both, input, dry_run);
-        duration_ms
+    #[test]
+    fn parsed_both_takes_the_parsed_input() {
+        let puzzle = puzzle().with_parsed_both(|numbers: &Vec<i64>| {
+            let (min, max) = (numbers.iter().min(), numbers.iter().max());
+            Ok((Answer::Int(*min.unwrap()), Answer::Int(*max.unwrap())))
+        });
+        assert!(puzzle.has_both());
+        assert_eq!(
+            puzzle.run_part(PuzzlePart::Both, "3,2,4").unwrap(),
+            vec![Answer::Int(2), Answer::Int(4)]
+        );
+
+        let mut solver = puzzle.solver("3,2,4", &SolveOptions::default());
+        let both = solver.solve(PuzzlePart::Both);
+        assert_eq!(both.answers, vec![Answer::Int(2), Answer::Int(4)]);
+        assert!(both.parse_ms.is_some());
+        assert_eq!(
+            solver.solve(PuzzlePart::Silver).answers,
+            vec![Answer::Int(9)]
+        );
+    }
+
+    #[test]
+    fn parse_errors_fail_every_part() {
+        let puzzle = puzzle().skip(PuzzlePart::Gold, "Too slow");
+        let mut solver = puzzle.solver("2,x", &SolveOptions::default());
+        let error = Some(PuzzleError::parse(1, 3, "invalid number"));
+
+        let silver = solver.solve(PuzzlePart::Silver);
+        assert_eq!(silver.puzzle_error, error);
+        assert!(silver.parse_ms.is_some());
+        assert_eq!(solver.solve(PuzzlePart::Gold).skipped, Some("Too slow"));
+        assert_eq!(solver.solve(PuzzlePart::Both).puzzle_error, error);
     }
 }
@@ -0,0 +1,287 @@
+use crate::aoc_puzzle::{PartResult, PuzzlePart};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+pub const BASELINE_DIR: &str = "./baselines";
+
+/// The build profile of this binary. Debug timings are nothing like release ones.
+const BUILD: &str = if cfg!(debug_assertions) {
+    "debug"
+} else {
+    "release"
+};
+
+#[derive(Serialize, Deserialize)]
+struct BaselineEntry {
+    year: u16,
+    day: u8,
+    part: PuzzlePart,
+    duration_ms: f64,
+}
+
+/// What the timings of a baseline depend on besides the solutions themselves: the build
+/// profile, and the input set given with `--profile`. Baselines are only compared and added
+/// to from runs with the same ones.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BaselineSource {
+    pub build: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+}
+
+impl BaselineSource {
+    /// The source of the timings of this binary on the given input set.
+    pub fn current(profile: Option<String>) -> Self {
+        Self {
+            build: BUILD.to_string(),
+            profile,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match &self.profile {
+            Some(profile) => format!("a {} build on input set {}", self.build, profile),
+            None => format!("a {} build on the default inputs", self.build),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    #[serde(flatten)]
+    source: BaselineSource,
+    timings: Vec<BaselineEntry>,
+}
+
+/// How a part's timing compares to the same part in a saved baseline.
+#[derive(Clone, Copy, Serialize)]
+pub struct BaselineDelta {
+    pub baseline_ms: f64,
+    pub delta_ms: f64,
+    pub delta_pct: f64,
+    pub regressed: bool,
+}
+
+#[derive(Clone, Default, Serialize)]
+pub struct BaselineSummary {
+    pub name: String,
+    pub baseline_ms: f64,
+    pub current_ms: f64,
+    pub compared: usize,
+    pub regressed: usize,
+}
+
+/// Per-part timings saved under a name in `./baselines/<name>.json`.
+pub struct Baseline {
+    name: String,
+    source: BaselineSource,
+    timings: BTreeMap<(u16, u8, PuzzlePart), f64>,
+}
+
+impl Baseline {
+    fn path(name: &str) -> Result<PathBuf, String> {
+        if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+            return Err(format!("Invalid baseline name: {}", name));
+        }
+        Ok(PathBuf::from(BASELINE_DIR).join(format!("{}.json", name)))
+    }
+
+    /// Loads a baseline to compare or add the timings of a run from `source` to, which fails
+    /// when the baseline was saved from another build profile or input set.
+    pub fn load(name: &str, source: &BaselineSource) -> Result<Self, String> {
+        let path = Self::path(name)?;
+        let content = fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to read baseline {}: {}", path.display(), err))?;
+        Self::parse(name, &content, source)
+            .map_err(|err| format!("Baseline {}: {}", path.display(), err))
+    }
+
+    fn parse(name: &str, content: &str, source: &BaselineSource) -> Result<Self, String> {
+        let value: serde_json::Value =
+            serde_json::from_str(content).map_err(|err| err.to_string())?;
+        // Baselines used to be a plain list of timings, without their source
+        if value.is_array() {
+            return Err("saved without its build profile and input set, save it again".to_string());
+        }
+        let file: BaselineFile = serde_json::from_value(value).map_err(|err| err.to_string())?;
+        if file.source != *source {
+            return Err(format!(
+                "saved from {}, which can't be compared with {}",
+                file.source.describe(),
+                source.describe()
+            ));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            source: file.source,
+            timings: file
+                .timings
+                .into_iter()
+                .map(|e| ((e.year, e.day, e.part), e.duration_ms))
+                .collect(),
+        })
+    }
+
+    /// Loads an existing baseline so that saving only overwrites the parts that were run,
+    /// or starts an empty one if it doesn't exist yet.
+    pub fn load_or_new(name: &str, source: &BaselineSource) -> Result<Self, String> {
+        if Self::path(name)?.exists() {
+            Self::load(name, source)
+        } else {
+            Ok(Self {
+                name: name.to_string(),
+                source: source.clone(),
+                timings: BTreeMap::new(),
+            })
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn record(&mut self, result: &PartResult) {
+        if result.skipped.is_none() && !result.answers.is_empty() {
+            self.timings
+                .insert((result.year, result.day, result.part), result.duration_ms);
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::path(&self.name)?;
+        let file = BaselineFile {
+            source: self.source.clone(),
+            timings: self
+                .timings
+                .iter()
+                .map(|(&(year, day, part), &duration_ms)| BaselineEntry {
+                    year,
+                    day,
+                    part,
+                    duration_ms,
+                })
+                .collect(),
+        };
+
+        fs::create_dir_all(BASELINE_DIR)
+            .and_then(|_| {
+                fs::write(
+                    &path,
+                    serde_json::to_string_pretty(&file).expect("Failed to serialize baseline"),
+                )
+            })
+            .map_err(|err| format!("Failed to write baseline {}: {}", path.display(), err))
+    }
+
+    /// Compares a result against this baseline and records the delta on it. A part counts as
+    /// regressed when it is more than `threshold_pct` percent slower than the baseline.
+    pub fn compare(&self, result: &mut PartResult, threshold_pct: f64) -> Option<BaselineDelta> {
+        if result.skipped.is_some() || result.answers.is_empty() {
+            return None;
+        }
+
+        let baseline_ms = *self.timings.get(&(result.year, result.day, result.part))?;
+        let delta_ms = result.duration_ms - baseline_ms;
+        let delta_pct = if baseline_ms > 0.0 {
+            delta_ms / baseline_ms * 100.0
+        } else {
+            0.0
+        };
+
+        let delta = BaselineDelta {
+            baseline_ms,
+            delta_ms,
+            delta_pct,
+            regressed: delta_pct > threshold_pct,
+        };
+        result.baseline = Some(delta);
+        Some(delta)
+    }
+}
+
+impl BaselineSummary {
+    pub fn add(&mut self, delta: &BaselineDelta, current_ms: f64) {
+        self.baseline_ms += delta.baseline_ms;
+        self.current_ms += current_ms;
+        self.compared += 1;
+        if delta.regressed {
+            self.regressed += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    fn result(duration_ms: f64) -> PartResult {
+        let mut result = PartResult::new(2025, 1, PuzzlePart::Silver);
+        result.answers = vec![Answer::Int(999)];
+        result.duration_ms = duration_ms;
+        result
+    }
+
+    #[test]
+    fn compare_against_baseline() {
+        let mut baseline = Baseline {
+            name: "test".to_string(),
+            source: BaselineSource::current(None),
+            timings: BTreeMap::new(),
+        };
+        baseline.record(&result(2.0));
+
+        let delta = baseline.compare(&mut result(2.1), 10.0).unwrap();
+        assert!((delta.delta_pct - 5.0).abs() < 1e-9);
+        assert!(!delta.regressed);
+
+        let delta = baseline.compare(&mut result(3.0), 10.0).unwrap();
+        assert!((delta.delta_ms - 1.0).abs() < 1e-9);
+        assert!(delta.regressed);
+    }
+
+    #[test]
+    fn only_compare_the_same_source() {
+        let release = BaselineSource {
+            build: "release".to_string(),
+            profile: None,
+        };
+        let content = r#"{
+            "build": "release",
+            "timings": [{ "year": 2025, "day": 1, "part": "silver", "duration_ms": 2.0 }]
+        }"#;
+        let baseline = Baseline::parse("before", content, &release).unwrap();
+        assert_eq!(baseline.timings.len(), 1);
+
+        let debug = BaselineSource {
+            build: "debug".to_string(),
+            ..release.clone()
+        };
+        assert_eq!(
+            Baseline::parse("before", content, &debug).err().unwrap(),
+            "saved from a release build on the default inputs, which can't be compared with a \
+             debug build on the default inputs"
+        );
+        let alice = BaselineSource {
+            profile: Some("alice".to_string()),
+            ..release.clone()
+        };
+        assert!(Baseline::parse("before", content, &alice).is_err());
+
+        let unsourced = r#"[{ "year": 2025, "day": 1, "part": "silver", "duration_ms": 2.0 }]"#;
+        assert!(Baseline::parse("before", unsourced, &release)
+            .err()
+            .unwrap()
+            .contains("save it again"));
+    }
+
+    #[test]
+    fn invalid_baseline_name() {
+        assert!(Baseline::path("../secret").is_err());
+        assert!(Baseline::path("").is_err());
+        assert!(Baseline::path("before-refactor").is_ok());
+    }
+}
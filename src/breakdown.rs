@@ -0,0 +1,257 @@
+//! The timing report of `--summary`: where the time of a run went, by year and by part, which
+//! parts were the slowest, what the dedicated `both` solutions save, and what was skipped.
+
+use crate::aoc_puzzle::{AocPuzzle, PartResult, PuzzlePart};
+use crate::examples::NO_ANSWER;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet};
+
+#[derive(Clone, Serialize)]
+pub struct YearTotal {
+    pub year: u16,
+    /// The time of the parts, and of parsing their inputs
+    pub duration_ms: f64,
+    pub parts: usize,
+}
+
+#[derive(Clone, Serialize)]
+pub struct PartTotal {
+    pub part: PuzzlePart,
+    pub duration_ms: f64,
+    pub parts: usize,
+}
+
+#[derive(Clone, Serialize)]
+pub struct SlowPart {
+    pub year: u16,
+    pub day: u8,
+    pub part: PuzzlePart,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+    pub duration_ms: f64,
+}
+
+/// The time of a dedicated `both` solution on an input, against silver and gold one after the
+/// other. `saved_ms` is negative when `both` is the slower one.
+#[derive(Clone, Serialize)]
+pub struct BothSavings {
+    pub year: u16,
+    pub day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+    pub both_ms: f64,
+    pub separate_ms: f64,
+    pub saved_ms: f64,
+}
+
+#[derive(Clone, Serialize)]
+pub struct SkipCount {
+    pub reason: &'static str,
+    pub parts: usize,
+}
+
+#[derive(Clone, Default, Serialize)]
+pub struct TimingBreakdown {
+    pub years: Vec<YearTotal>,
+    pub parts: Vec<PartTotal>,
+    pub slowest: Vec<SlowPart>,
+    pub both_savings: Vec<BothSavings>,
+    pub skipped: Vec<SkipCount>,
+}
+
+/// Collects the results of a run into a `TimingBreakdown`. Parts count once they ran, whether
+/// or not they found an answer, but only solved parts are compared against `both`.
+pub struct BreakdownBuilder {
+    top: usize,
+    dedicated: HashSet<(u16, u8)>,
+    years: BTreeMap<u16, (f64, usize)>,
+    parts: BTreeMap<PuzzlePart, (f64, usize)>,
+    ran: Vec<SlowPart>,
+    both: BTreeMap<(u16, u8, Option<String>), [Option<f64>; 3]>,
+    skipped: BTreeMap<&'static str, usize>,
+}
+
+impl BreakdownBuilder {
+    /// Prepares a breakdown of the given puzzles that lists the `top` slowest parts.
+    pub fn new<'a>(puzzles: impl IntoIterator<Item = &'a AocPuzzle>, top: usize) -> Self {
+        Self {
+            top,
+            dedicated: puzzles
+                .into_iter()
+                .filter(|puzzle| puzzle.has_both())
+                .map(|puzzle| (puzzle.year, puzzle.day))
+                .collect(),
+            years: BTreeMap::new(),
+            parts: BTreeMap::new(),
+            ran: Vec::new(),
+            both: BTreeMap::new(),
+            skipped: BTreeMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, result: &PartResult) {
+        if let Some(reason) = result.skipped {
+            // Examples often only apply to one of the parts, which isn't worth counting
+            if reason != NO_ANSWER {
+                *self.skipped.entry(reason).or_default() += 1;
+            }
+            return;
+        }
+
+        let year = self.years.entry(result.year).or_default();
+        year.0 += result.duration_ms + result.parse_ms.unwrap_or_default();
+        year.1 += 1;
+        let part = self.parts.entry(result.part).or_default();
+        part.0 += result.duration_ms;
+        part.1 += 1;
+        self.ran.push(SlowPart {
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            input: result.input.clone(),
+            duration_ms: result.duration_ms,
+        });
+
+        if !result.answers.is_empty() && self.dedicated.contains(&(result.year, result.day)) {
+            let key = (result.year, result.day, result.input.clone());
+            self.both.entry(key).or_default()[result.part as usize] = Some(result.duration_ms);
+        }
+    }
+
+    pub fn finish(mut self) -> TimingBreakdown {
+        self.ran
+            .sort_by(|a, b| b.duration_ms.total_cmp(&a.duration_ms));
+        self.ran.truncate(self.top);
+
+        let mut skipped: Vec<SkipCount> = self
+            .skipped
+            .into_iter()
+            .map(|(reason, parts)| SkipCount { reason, parts })
+            .collect();
+        // Most skipped first, and by reason for the same count, as the map is sorted by reason
+        skipped.sort_by_key(|count| Reverse(count.parts));
+
+        TimingBreakdown {
+            years: self
+                .years
+                .into_iter()
+                .map(|(year, (duration_ms, parts))| YearTotal {
+                    year,
+                    duration_ms,
+                    parts,
+                })
+                .collect(),
+            parts: self
+                .parts
+                .into_iter()
+                .map(|(part, (duration_ms, parts))| PartTotal {
+                    part,
+                    duration_ms,
+                    parts,
+                })
+                .collect(),
+            slowest: self.ran,
+            both_savings: self
+                .both
+                .into_iter()
+                .filter_map(|((year, day, input), durations)| {
+                    let [Some(silver), Some(gold), Some(both_ms)] = durations else {
+                        return None;
+                    };
+                    Some(BothSavings {
+                        year,
+                        day,
+                        input,
+                        both_ms,
+                        separate_ms: silver + gold,
+                        saved_ms: silver + gold - both_ms,
+                    })
+                })
+                .collect(),
+            skipped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    fn result(year: u16, day: u8, part: PuzzlePart, duration_ms: f64) -> PartResult {
+        let mut result = PartResult::new(year, day, part);
+        result.answers = vec![Answer::Int(1)];
+        result.duration_ms = duration_ms;
+        result
+    }
+
+    #[test]
+    fn break_down_a_run() {
+        let dedicated = AocPuzzle::new(2025, 8, |_| Ok(Answer::Int(1)), |_| Ok(Answer::Int(2)))
+            .with_both(|_| Ok((Answer::Int(1), Answer::Int(2))));
+        let separate = AocPuzzle::new(2019, 2, |_| Ok(Answer::Int(1)), |_| Ok(Answer::Int(2)));
+        let mut builder = BreakdownBuilder::new([&dedicated, &separate], 2);
+
+        let mut parsed = result(2019, 2, PuzzlePart::Silver, 1.0);
+        parsed.parse_ms = Some(0.5);
+        let mut skipped = PartResult::new(2019, 2, PuzzlePart::Both);
+        skipped.skipped = Some("Too slow");
+        let mut example = PartResult::new(2019, 2, PuzzlePart::Gold);
+        example.skipped = Some(NO_ANSWER);
+        for result in [
+            parsed,
+            result(2019, 2, PuzzlePart::Gold, 4.0),
+            skipped.clone(),
+            example,
+            result(2025, 8, PuzzlePart::Silver, 2.0),
+            result(2025, 8, PuzzlePart::Gold, 3.0),
+            result(2025, 8, PuzzlePart::Both, 4.0),
+            skipped,
+        ] {
+            builder.add(&result);
+        }
+        let breakdown = builder.finish();
+
+        let years: Vec<(u16, f64, usize)> = breakdown
+            .years
+            .iter()
+            .map(|total| (total.year, total.duration_ms, total.parts))
+            .collect();
+        assert_eq!(years, vec![(2019, 5.5, 2), (2025, 9.0, 3)]);
+        let parts: Vec<(PuzzlePart, f64)> = breakdown
+            .parts
+            .iter()
+            .map(|total| (total.part, total.duration_ms))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                (PuzzlePart::Silver, 3.0),
+                (PuzzlePart::Gold, 7.0),
+                (PuzzlePart::Both, 4.0)
+            ]
+        );
+
+        let slowest: Vec<(u16, PuzzlePart)> = breakdown
+            .slowest
+            .iter()
+            .map(|part| (part.year, part.part))
+            .collect();
+        assert_eq!(
+            slowest,
+            vec![(2019, PuzzlePart::Gold), (2025, PuzzlePart::Both)]
+        );
+
+        assert_eq!(breakdown.both_savings.len(), 1);
+        assert_eq!(breakdown.both_savings[0].day, 8);
+        assert_eq!(breakdown.both_savings[0].separate_ms, 5.0);
+        assert_eq!(breakdown.both_savings[0].saved_ms, 1.0);
+
+        assert_eq!(breakdown.skipped.len(), 1);
+        assert_eq!(
+            (breakdown.skipped[0].reason, breakdown.skipped[0].parts),
+            ("Too slow", 2)
+        );
+    }
+}
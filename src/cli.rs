@@ -0,0 +1,515 @@
+use crate::aoc_client::{ClientConfig, DEFAULT_CONFIG_PATH};
+use crate::aoc_puzzle::{AocPuzzle, PuzzlePart};
+use crate::inputs::{InputSet, PuzzleInput, DEFAULT_INPUT_DIR, INPUT_DIR_ENV, STDIN_PATH};
+use crate::puzzle_collection::get_puzzles;
+use crate::reporter::OutputFormat;
+use clap::{value_parser, Args, Parser, Subcommand};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "aoc")]
+#[command(about = "Advent of Code solutions")]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Without a subcommand, the options of `run` apply
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run the selected puzzles and print the answers (default)
+    Run(RunArgs),
+    /// Show a year × day grid of the implemented puzzles, skipped parts and missing inputs
+    List(ListArgs),
+    /// Check the answers against an expected answers file, failing on any mismatch
+    Verify(VerifyArgs),
+    /// Run each part repeatedly and report timing statistics
+    Bench(BenchArgs),
+    /// Create the skeleton module and an empty input file for a new day
+    New(NewArgs),
+    /// Run the selected puzzles and print only the summary
+    Stats(StatsArgs),
+    /// Download the missing inputs of the selected puzzles into the input directory
+    Fetch(FetchArgs),
+    /// Solve a part and submit its answer, unless an earlier submission rules it out
+    Submit(SubmitArgs),
+    /// Rerun the selected puzzles whenever their input or example files change
+    Watch(WatchArgs),
+}
+
+#[derive(Args)]
+pub struct PuzzleFilter {
+    /// Specify the year (e.g., 2025)
+    #[arg(short = 'y', long, value_parser = value_parser!(u16).range(2015..))]
+    pub year: Option<u16>,
+
+    /// Specify the day (1-25)
+    #[arg(short = 'd', long, value_parser = value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+}
+
+#[derive(Args)]
+pub struct InputArgs {
+    /// Directory with the puzzle inputs, named YYYY_DD.txt
+    #[arg(long, value_name = "DIR", env = INPUT_DIR_ENV, default_value = DEFAULT_INPUT_DIR)]
+    pub input_dir: PathBuf,
+
+    /// Use the named input set in a subdirectory of the input directory, e.g. another account
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+}
+
+impl InputArgs {
+    pub fn input_set(&self) -> Result<InputSet, String> {
+        InputSet::new(&self.input_dir, self.profile.as_deref())
+    }
+}
+
+#[derive(Args)]
+pub struct Selection {
+    #[command(flatten)]
+    pub filter: PuzzleFilter,
+
+    #[command(flatten)]
+    pub inputs: InputArgs,
+
+    /// Run every input set: the input directory and each of its subdirectories
+    #[arg(long, conflicts_with = "profile")]
+    pub all_inputs: bool,
+
+    /// Parts to run, separated by commas: silver (part 1), gold (part 2) and both (the two parts
+    /// combined). Runs all three by default
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub part: Vec<PuzzlePart>,
+
+    /// Specify a custom input file path, or - to read standard input (requires --year and --day
+    /// to select a single puzzle)
+    #[arg(short = 'p', long, conflicts_with_all = ["profile", "all_inputs"])]
+    pub path: Option<String>,
+
+    /// Read the input from standard input, the same as --path -
+    #[arg(long, conflicts_with_all = ["path", "profile", "all_inputs"])]
+    pub stdin: bool,
+
+    /// Run the examples from the puzzle descriptions instead of the inputs, and check their
+    /// answers. Runs every example of a puzzle, or only example N
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        value_parser = value_parser!(u32).range(1..),
+        conflicts_with_all = ["path", "stdin", "profile", "all_inputs"]
+    )]
+    pub example: Option<Option<u32>>,
+}
+
+impl Selection {
+    pub fn parts(&self) -> Vec<PuzzlePart> {
+        if self.part.is_empty() {
+            vec![PuzzlePart::Silver, PuzzlePart::Gold, PuzzlePart::Both]
+        } else {
+            let mut parts = self.part.clone();
+            parts.sort();
+            parts.dedup();
+            parts
+        }
+    }
+
+    /// The input file given on the command line instead of an input set, `-` for stdin.
+    pub fn custom_path(&self) -> Option<&str> {
+        if self.stdin {
+            Some(STDIN_PATH)
+        } else {
+            self.path.as_deref()
+        }
+    }
+
+    pub fn puzzles(&self) -> Result<Vec<&'static AocPuzzle>, String> {
+        let puzzles = self.filter.puzzles()?;
+        if puzzles.len() > 1 {
+            let option = match self.custom_path() {
+                Some(STDIN_PATH) => "Reading the input from stdin",
+                Some(_) => "--path",
+                None => return Ok(puzzles),
+            };
+            return Err(format!(
+                "{} requires --year and --day to select a single puzzle, but {} puzzles match",
+                option,
+                puzzles.len()
+            ));
+        }
+        Ok(puzzles)
+    }
+
+    /// The input sets to run, the input directory itself unless a profile or all inputs are
+    /// selected.
+    pub fn input_sets(&self) -> Result<Vec<InputSet>, String> {
+        if self.all_inputs {
+            InputSet::discover(&self.inputs.input_dir)
+        } else {
+            Ok(vec![self.inputs.input_set()?])
+        }
+    }
+
+    /// The selected puzzles, paired with each input to run them on. With `--all-inputs`, only
+    /// the inputs that exist are included.
+    pub fn puzzle_inputs(&self) -> Result<Vec<PuzzleInput>, String> {
+        let puzzles = self.puzzles()?;
+        if let Some(path) = self.custom_path() {
+            return Ok(puzzles
+                .into_iter()
+                .map(|puzzle| PuzzleInput {
+                    puzzle,
+                    path: PathBuf::from(path),
+                    name: None,
+                    skip: Vec::new(),
+                })
+                .collect());
+        }
+
+        let mut inputs = Vec::new();
+        for set in self.input_sets()? {
+            for &puzzle in &puzzles {
+                let input = PuzzleInput::from_set(puzzle, &set);
+                if !self.all_inputs || input.path.is_file() {
+                    inputs.push(input);
+                }
+            }
+        }
+        if inputs.is_empty() {
+            return Err(format!(
+                "No inputs found for the selected puzzles in {}",
+                self.inputs.input_dir.display()
+            ));
+        }
+        Ok(inputs)
+    }
+}
+
+impl PuzzleFilter {
+    pub fn puzzles(&self) -> Result<Vec<&'static AocPuzzle>, String> {
+        let puzzles = get_puzzles(self.year, self.day);
+        if puzzles.is_empty() {
+            return Err("No puzzles found".to_string());
+        }
+        Ok(puzzles)
+    }
+}
+
+#[derive(Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// Maximum duration of each run of a part (e.g. 500ms, 10s, 5m), overriding the puzzle defaults
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+
+    /// Number of puzzles to run concurrently
+    #[arg(short = 'j', long, value_name = "N", default_value_t = 1, value_parser = value_parser!(u32).range(1..))]
+    pub jobs: u32,
+
+    /// Run the solution in dry run mode (no actual computation)
+    #[arg(long)]
+    pub dryrun: bool,
+
+    /// Print a summary
+    #[arg(short = 's', long)]
+    pub summary: bool,
+
+    /// Number of slowest parts to list in the summary
+    #[arg(long, value_name = "N", default_value_t = 5, requires = "summary")]
+    pub top: usize,
+
+    /// Output format for the results
+    #[arg(short = 'f', long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
+#[derive(Args)]
+pub struct ListArgs {
+    #[command(flatten)]
+    pub filter: PuzzleFilter,
+
+    #[command(flatten)]
+    pub inputs: InputArgs,
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// Specify the expected answers file [default: answers.toml in each input set]
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["all_inputs", "example"])]
+    pub answers: Option<PathBuf>,
+
+    /// Instead of the expected answers, check that each dedicated `both` solution agrees with silver and gold
+    #[arg(long, conflicts_with = "answers")]
+    pub check_both: bool,
+
+    /// Maximum duration of each run of a part (e.g. 500ms, 10s, 5m), overriding the puzzle defaults
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+
+    /// Number of puzzles to run concurrently
+    #[arg(short = 'j', long, value_name = "N", default_value_t = 1, value_parser = value_parser!(u32).range(1..))]
+    pub jobs: u32,
+
+    /// Output format for the results
+    #[arg(short = 'f', long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
+// Benchmarks always run one puzzle at a time, so that the timings are not disturbed
+#[derive(Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// Number of timed runs of each part (at least 2)
+    #[arg(short = 'n', long, value_name = "N", default_value_t = 10, value_parser = value_parser!(u32).range(2..))]
+    pub runs: u32,
+
+    /// Number of untimed warm-up runs of each part before measuring
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    pub warmup: u32,
+
+    /// Maximum duration of each run of a part (e.g. 500ms, 10s, 5m), overriding the puzzle defaults
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+
+    /// Save the timings as a named baseline
+    #[arg(long, value_name = "NAME")]
+    pub save_baseline: Option<String>,
+
+    /// Compare the timings with a previously saved baseline
+    #[arg(long, value_name = "NAME")]
+    pub compare_baseline: Option<String>,
+
+    /// Percentage slowdown compared to the baseline that counts as a regression
+    #[arg(
+        long,
+        value_name = "PCT",
+        default_value_t = 10.0,
+        requires = "compare_baseline"
+    )]
+    pub regression_threshold: f64,
+
+    /// Output format for the results
+    #[arg(short = 'f', long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// The year of the new puzzle
+    #[arg(short = 'y', long, value_parser = value_parser!(u16).range(2015..))]
+    pub year: u16,
+
+    /// The day of the new puzzle (1-25)
+    #[arg(short = 'd', long, value_parser = value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    #[command(flatten)]
+    pub inputs: InputArgs,
+}
+
+#[derive(Args)]
+pub struct StatsArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// Number of slowest parts to list in the summary
+    #[arg(long, value_name = "N", default_value_t = 5)]
+    pub top: usize,
+
+    /// Maximum duration of each run of a part (e.g. 500ms, 10s, 5m), overriding the puzzle defaults
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Args)]
+pub struct FetchArgs {
+    #[command(flatten)]
+    pub filter: PuzzleFilter,
+
+    #[command(flatten)]
+    pub inputs: InputArgs,
+
+    #[command(flatten)]
+    pub client: ClientArgs,
+}
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// The year of the puzzle
+    #[arg(short = 'y', long, value_parser = value_parser!(u16).range(2015..))]
+    pub year: u16,
+
+    /// The day of the puzzle (1-25)
+    #[arg(short = 'd', long, value_parser = value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// The part to submit: silver (part 1) or gold (part 2)
+    #[arg(long, value_name = "PART", value_parser = parse_submit_part)]
+    pub part: PuzzlePart,
+
+    #[command(flatten)]
+    pub inputs: InputArgs,
+
+    #[command(flatten)]
+    pub client: ClientArgs,
+}
+
+#[derive(Args)]
+pub struct WatchArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// How often to check the files for changes (e.g. 500ms, 2s)
+    #[arg(long, value_name = "DURATION", default_value = "500ms", value_parser = parse_duration)]
+    pub interval: Duration,
+
+    /// Maximum duration of each run of a part (e.g. 500ms, 10s, 5m), overriding the puzzle defaults
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+}
+
+/// How to reach the Advent of Code server, for the commands that talk to it.
+#[derive(Args)]
+pub struct ClientArgs {
+    /// Configuration file with the session token and base URL
+    #[arg(long, value_name = "PATH", default_value = DEFAULT_CONFIG_PATH)]
+    pub config: String,
+
+    /// Base URL of the server, overriding AOC_BASE_URL and the config file
+    #[arg(long, value_name = "URL")]
+    pub base_url: Option<String>,
+
+    /// Minimum time between two requests to the server (e.g. 500ms, 10s, 5m)
+    #[arg(long, value_name = "DURATION", default_value = "3s", value_parser = parse_duration)]
+    pub interval: Duration,
+}
+
+impl ClientArgs {
+    pub fn config(self) -> Result<ClientConfig, String> {
+        ClientConfig::load(&self.config, self.base_url, self.interval)
+    }
+}
+
+// Answers are submitted per part, so `both` has no answer of its own to submit
+fn parse_submit_part(value: &str) -> Result<PuzzlePart, String> {
+    match value {
+        "silver" => Ok(PuzzlePart::Silver),
+        "gold" => Ok(PuzzlePart::Gold),
+        _ => Err(format!("invalid part '{}', expected silver or gold", value)),
+    }
+}
+
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let (number, unit) = match value.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(i) => value.split_at(i),
+        None => (value, "s"),
+    };
+    let number: f64 = number
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid duration '{}'", value))?;
+    let seconds = match unit {
+        "ms" => number / 1000.0,
+        "s" => number,
+        "m" => number * 60.0,
+        _ => return Err(format!("invalid duration unit '{}', use ms, s or m", unit)),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("invalid duration '{}'", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn path_requires_a_single_puzzle() {
+        let cli = Cli::try_parse_from(["aoc", "run", "-y", "2025", "-p", "input.txt"]).unwrap();
+        let Some(Command::Run(args)) = cli.command else {
+            panic!("expected the run command");
+        };
+        assert!(args.selection.puzzles().is_err());
+
+        let cli = Cli::try_parse_from(["aoc", "-y", "2025", "--stdin"]).unwrap();
+        assert!(cli.run.selection.puzzles().is_err());
+        let cli = Cli::try_parse_from(["aoc", "-y", "2025", "-d", "1", "--stdin"]).unwrap();
+        let inputs = cli.run.selection.puzzle_inputs().unwrap();
+        assert_eq!(inputs[0].path, PathBuf::from("-"));
+        assert!(Cli::try_parse_from(["aoc", "--stdin", "-p", "input.txt"]).is_err());
+    }
+
+    #[test]
+    fn input_options() {
+        let cli = Cli::try_parse_from([
+            "aoc",
+            "-y",
+            "2025",
+            "-d",
+            "1",
+            "--input-dir",
+            "inputs",
+            "--profile",
+            "alice",
+        ])
+        .unwrap();
+        let inputs = cli.run.selection.puzzle_inputs().unwrap();
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].path, PathBuf::from("inputs/alice/2025_01.txt"));
+        assert_eq!(inputs[0].name.as_deref(), Some("alice"));
+
+        assert!(Cli::try_parse_from(["aoc", "--profile", "alice", "--all-inputs"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "-p", "input.txt", "--profile", "alice"]).is_err());
+    }
+
+    #[test]
+    fn parts_are_deduplicated_in_order() {
+        let cli =
+            Cli::try_parse_from(["aoc", "--part", "both,silver", "--part", "silver"]).unwrap();
+        assert_eq!(
+            cli.run.selection.parts(),
+            vec![PuzzlePart::Silver, PuzzlePart::Both]
+        );
+    }
+
+    #[test]
+    fn submit_takes_a_single_part() {
+        let submit = ["aoc", "submit", "-y", "2025", "-d", "1", "--part"];
+        let cli = Cli::try_parse_from(submit.iter().chain(&["gold"])).unwrap();
+        let Some(Command::Submit(args)) = cli.command else {
+            panic!("expected the submit command");
+        };
+        assert_eq!(args.part, PuzzlePart::Gold);
+
+        assert!(Cli::try_parse_from(submit.iter().chain(&["both"])).is_err());
+        assert!(Cli::try_parse_from(["aoc", "submit", "-y", "2025", "--part", "silver"]).is_err());
+    }
+
+    #[test]
+    fn parse_durations() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("10s"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("3"), Ok(Duration::from_secs(3)));
+        assert!(parse_duration("10h").is_err());
+        assert!(parse_duration("fast").is_err());
+    }
+}
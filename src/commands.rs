@@ -0,0 +1,589 @@
+//! The commands of the `aoc` binary, on top of the puzzles and the runner.
+
+use crate::answers::{BothCheck, ExpectedAnswers, VerificationCounts};
+use crate::aoc_client::{self, AocClient, SubmitOutcome};
+use crate::aoc_puzzle::{PuzzlePart, SolveOptions};
+use crate::baseline::{Baseline, BaselineSource, BaselineSummary};
+use crate::breakdown::BreakdownBuilder;
+use crate::cli::{
+    BenchArgs, Cli, Command, FetchArgs, ListArgs, NewArgs, RunArgs, Selection, StatsArgs,
+    SubmitArgs, VerifyArgs, WatchArgs,
+};
+use crate::coverage;
+use crate::examples::{Examples, EXAMPLES_DIR};
+use crate::inputs::{read_input_file, PuzzleInput, ANSWERS_FILE, STDIN_PATH};
+use crate::memory::{self, MemoryTotals};
+use crate::panics;
+use crate::puzzle_collection::get_puzzles;
+use crate::reporter::{create_reporter, OutputFormat, ReportOptions, RunSummary};
+use crate::runner::run_puzzles;
+use crate::scaffold;
+use crate::stats::TimingTotals;
+use crate::submissions::{Ledger, Submission};
+use crate::watch::{self, FileWatcher, History};
+use std::collections::HashMap;
+use std::io::{stdout, IsTerminal};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Runs the command given on the command line, returning an error for a command that can't be
+/// carried out, such as a missing input.
+pub fn dispatch(cli: Cli) -> Result<ExitCode, String> {
+    panics::install_hook();
+
+    match cli.command.unwrap_or(Command::Run(cli.run)) {
+        Command::Run(args) => run(args),
+        Command::List(args) => list(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::New(args) => new_day(args),
+        Command::Stats(args) => stats(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Watch(args) => watch(args),
+    }
+}
+
+fn run(args: RunArgs) -> Result<ExitCode, String> {
+    execute(
+        &args.selection,
+        RunPlan {
+            solve_options: SolveOptions {
+                dry_run: args.dryrun,
+                timeout: args.timeout,
+                ..SolveOptions::default()
+            },
+            jobs: args.jobs as usize,
+            format: args.format,
+            summary: args.summary,
+            breakdown: args.summary.then_some(args.top),
+            ..RunPlan::default()
+        },
+    )
+}
+
+fn verify(args: VerifyArgs) -> Result<ExitCode, String> {
+    execute(
+        &args.selection,
+        RunPlan {
+            solve_options: SolveOptions {
+                timeout: args.timeout,
+                ..SolveOptions::default()
+            },
+            jobs: args.jobs as usize,
+            format: args.format,
+            summary: true,
+            // The answers of the examples are loaded along with them
+            expected_answers: if args.check_both || args.selection.example.is_some() {
+                None
+            } else {
+                Some(load_expected_answers(&args)?)
+            },
+            check_both: args.check_both,
+            ..RunPlan::default()
+        },
+    )
+}
+
+/// The expected answers of each input set, from `--answers` or the `answers.toml` in the set.
+/// When running all input sets, a set without answers file has nothing to verify against.
+fn load_expected_answers(
+    args: &VerifyArgs,
+) -> Result<HashMap<Option<String>, ExpectedAnswers>, String> {
+    let selection = &args.selection;
+    let mut expected = HashMap::new();
+    if let Some(path) = &args.answers {
+        let profile = match selection.custom_path() {
+            Some(_) => None,
+            None => selection.inputs.profile.clone(),
+        };
+        expected.insert(profile, ExpectedAnswers::load(path)?);
+        return Ok(expected);
+    }
+    for set in selection.input_sets()? {
+        let path = set.answers_path();
+        let answers = if selection.all_inputs && !path.is_file() {
+            ExpectedAnswers::default()
+        } else {
+            ExpectedAnswers::load(&path)?
+        };
+        let profile = match selection.custom_path() {
+            Some(_) => None,
+            None => set.profile,
+        };
+        expected.insert(profile, answers);
+    }
+    Ok(expected)
+}
+
+fn bench(args: BenchArgs) -> Result<ExitCode, String> {
+    let single_input = !args.selection.all_inputs && args.selection.example.is_none();
+    if !single_input && (args.save_baseline.is_some() || args.compare_baseline.is_some()) {
+        return Err("Baselines can only be used with a single input set".to_string());
+    }
+    let source = BaselineSource::current(match args.selection.custom_path() {
+        Some(_) => None,
+        None => args.selection.inputs.profile.clone(),
+    });
+    execute(
+        &args.selection,
+        RunPlan {
+            solve_options: SolveOptions {
+                warmup: args.warmup,
+                iterations: args.runs,
+                timeout: args.timeout,
+                ..SolveOptions::default()
+            },
+            format: args.format,
+            summary: true,
+            bench: true,
+            compare_baseline: args
+                .compare_baseline
+                .as_deref()
+                .map(|name| Baseline::load(name, &source))
+                .transpose()?,
+            regression_threshold: args.regression_threshold,
+            save_baseline: args
+                .save_baseline
+                .as_deref()
+                .map(|name| Baseline::load_or_new(name, &source))
+                .transpose()?,
+            ..RunPlan::default()
+        },
+    )
+}
+
+fn stats(args: StatsArgs) -> Result<ExitCode, String> {
+    execute(
+        &args.selection,
+        RunPlan {
+            solve_options: SolveOptions {
+                timeout: args.timeout,
+                ..SolveOptions::default()
+            },
+            summary: true,
+            breakdown: Some(args.top),
+            rows: false,
+            ..RunPlan::default()
+        },
+    )
+}
+
+fn list(args: ListArgs) -> Result<ExitCode, String> {
+    let input_set = args.inputs.input_set()?;
+    let puzzles = get_puzzles(None, None);
+    let years: Vec<u16> = match args.filter.year {
+        Some(year) => vec![year],
+        None => {
+            let first = puzzles.iter().map(|p| p.year).min().unwrap_or_default();
+            let last = puzzles.iter().map(|p| p.year).max().unwrap_or_default();
+            (first..=last).collect()
+        }
+    };
+    let days: Vec<u8> = match args.filter.day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    };
+    print!(
+        "{}",
+        coverage::render(
+            &puzzles,
+            &years,
+            &days,
+            &input_set,
+            aoc_client::has_content,
+            stdout().is_terminal()
+        )
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+fn fetch(args: FetchArgs) -> Result<ExitCode, String> {
+    let input_set = args.inputs.input_set()?;
+    let puzzles = args.filter.puzzles()?;
+    let (cached, missing): (Vec<_>, Vec<_>) = puzzles
+        .into_iter()
+        .partition(|puzzle| aoc_client::has_content(&input_set.path(puzzle.year, puzzle.day)));
+    for puzzle in cached {
+        println!("{} day {:>2}: cached", puzzle.year, puzzle.day);
+    }
+    if missing.is_empty() {
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut client = AocClient::new(args.client.config()?);
+    let mut failed = false;
+    for puzzle in missing {
+        let path = input_set.path(puzzle.year, puzzle.day);
+        match client.cache_input(puzzle.year, puzzle.day, &path) {
+            Ok(_) => println!(
+                "{} day {:>2}: downloaded to {}",
+                puzzle.year,
+                puzzle.day,
+                path.display()
+            ),
+            Err(err) => {
+                eprintln!("{} day {:>2}: {}", puzzle.year, puzzle.day, err);
+                failed = true;
+            }
+        }
+    }
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn submit(args: SubmitArgs) -> Result<ExitCode, String> {
+    let (year, day, part) = (args.year, args.day, args.part);
+    let label = format!("{} day {} {}", year, day, part.as_str());
+    let puzzle = *get_puzzles(Some(year), Some(day))
+        .first()
+        .ok_or_else(|| format!("No solution for {} day {}", year, day))?;
+    let input_set = args.inputs.input_set()?;
+    let input = read_input_file(&input_set.path(year, day))?;
+
+    let result = puzzle.solve_part(part, &input, &SolveOptions::default());
+    if let Some(reason) = result.skipped {
+        return Err(format!("{} is skipped: {}", label, reason));
+    }
+    let answer = match (result.answers.first(), result.error, result.puzzle_error) {
+        (Some(answer), _, _) => answer.to_string(),
+        (None, Some(err), _) => return Err(format!("{} failed: {}", label, err)),
+        (None, None, Some(err)) => return Err(format!("{} failed: {}", label, err)),
+        (None, None, None) => return Err(format!("{} has no answer", label)),
+    };
+
+    let mut ledger = Ledger::load(&input_set.submissions_path())?;
+    if let Some(right) = ledger.right_answer(year, day, part) {
+        if right == answer {
+            println!("{}: {} was already accepted", label, answer);
+            return Ok(ExitCode::SUCCESS);
+        }
+        return Err(format!(
+            "{} was already solved with {}, not submitting {}",
+            label, right, answer
+        ));
+    }
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    ledger
+        .check(year, day, part, &answer, now)
+        .map_err(|reason| format!("Not submitting {} for {}: {}", answer, label, reason))?;
+
+    let mut client = AocClient::new(args.client.config()?);
+    let level = if part == PuzzlePart::Silver { 1 } else { 2 };
+    let response = client.submit_answer(year, day, level, &answer)?;
+    ledger.record(Submission {
+        year,
+        day,
+        part,
+        answer: answer.clone(),
+        outcome: response.outcome,
+        submitted_at: now,
+        wait_s: response.wait.map(|wait| wait.as_secs()),
+    });
+    ledger.save()?;
+
+    let verdict = match response.outcome {
+        SubmitOutcome::Right => "is the right answer",
+        SubmitOutcome::Wrong => "is not the right answer",
+        SubmitOutcome::TooHigh => "is too high",
+        SubmitOutcome::TooLow => "is too low",
+        SubmitOutcome::RateLimited => "was not checked, an answer was submitted too recently",
+        SubmitOutcome::WrongLevel => "was not checked, the part is already solved or not open yet",
+    };
+    match response.wait {
+        Some(wait) => println!(
+            "{}: {} {} (wait {}s before the next answer)",
+            label,
+            answer,
+            verdict,
+            wait.as_secs()
+        ),
+        None => println!("{}: {} {}", label, answer, verdict),
+    }
+    Ok(if response.outcome == SubmitOutcome::Right {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Runs the selected puzzles, then reruns each input whenever its file changes, and all the
+/// examples when their answers do, until interrupted. A selection that can't be run, such as
+/// an example that doesn't exist yet, is reported and checked again on the next poll.
+fn watch(args: WatchArgs) -> Result<ExitCode, String> {
+    if args.selection.custom_path() == Some(STDIN_PATH) {
+        return Err("Watch mode can't read the input from stdin".to_string());
+    }
+    let options = SolveOptions {
+        timeout: args.timeout,
+        ..SolveOptions::default()
+    };
+    let parts = args.selection.parts();
+    let examples_dir = args.selection.inputs.input_dir.join(EXAMPLES_DIR);
+    let answers_path = examples_dir.join(ANSWERS_FILE);
+
+    let mut watcher = FileWatcher::default();
+    let mut history = History::default();
+    let mut last_error = None;
+    let mut first_run = true;
+    loop {
+        match watched_inputs(&args.selection) {
+            Ok(Watched {
+                inputs,
+                example_answers,
+            }) => {
+                last_error = None;
+                let paths = inputs.iter().map(|input| input.path.as_path());
+                let changed = watcher.poll(paths.chain([answers_path.as_path()]));
+                let answers_changed = changed.contains(&answers_path);
+                let to_run: Vec<PuzzleInput> = inputs
+                    .into_iter()
+                    .filter(|input| {
+                        changed.contains(&input.path)
+                            || (answers_changed && input.path.starts_with(&examples_dir))
+                    })
+                    .collect();
+
+                if !to_run.is_empty() {
+                    if first_run {
+                        println!(
+                            "Watching {} inputs for changes, press Ctrl-C to stop",
+                            to_run.len()
+                        );
+                        first_run = false;
+                    } else {
+                        let changed: Vec<String> = changed
+                            .iter()
+                            .map(|path| path.display().to_string())
+                            .collect();
+                        println!("----------------------------------------------------");
+                        println!("Changed: {}", changed.join(", "));
+                    }
+                    run_puzzles(&to_run, &parts, &options, 1, |mut result| {
+                        if let Some(answers) = example_answers.get(&result.input) {
+                            answers.verify(&mut result);
+                        }
+                        let change = history.record(&result);
+                        println!("{}", watch::row(&result, &change));
+                    });
+                }
+            }
+            Err(err) => {
+                if last_error.as_ref() != Some(&err) {
+                    eprintln!("{}", err);
+                    last_error = Some(err);
+                }
+            }
+        }
+        thread::sleep(args.interval);
+    }
+}
+
+/// The inputs of the selected puzzles and their examples, or only the examples with
+/// `--example`, along with the answers to check the examples against.
+struct Watched {
+    inputs: Vec<PuzzleInput>,
+    example_answers: HashMap<Option<String>, ExpectedAnswers>,
+}
+
+fn watched_inputs(selection: &Selection) -> Result<Watched, String> {
+    let puzzles = selection.puzzles()?;
+    let examples = Examples::load(&selection.inputs.input_dir)?;
+    let inputs = match selection.example {
+        Some(number) => examples.inputs(&puzzles, number)?,
+        None => {
+            // Puzzles don't need examples to be watched
+            let mut inputs = selection.puzzle_inputs()?;
+            inputs.extend(examples.inputs(&puzzles, None).unwrap_or_default());
+            inputs
+        }
+    };
+    Ok(Watched {
+        inputs,
+        example_answers: examples.expected_answers(),
+    })
+}
+
+fn new_day(args: NewArgs) -> Result<ExitCode, String> {
+    let input_set = args.inputs.input_set()?;
+    let example_dir = args.inputs.input_dir.join(EXAMPLES_DIR);
+    for path in scaffold::create_day(args.year, args.day, &input_set, &example_dir)? {
+        println!("Created {}", path.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// What to do with the results of a run, shared by the commands that solve puzzles.
+struct RunPlan {
+    solve_options: SolveOptions,
+    jobs: usize,
+    format: OutputFormat,
+    summary: bool,
+    /// Whether the summary breaks the timings down, and the number of slowest parts it lists
+    breakdown: Option<usize>,
+    bench: bool,
+    /// Whether to report the individual parts, or only the summary
+    rows: bool,
+    /// Expected answers of each input, keyed by the input set or example name
+    expected_answers: Option<HashMap<Option<String>, ExpectedAnswers>>,
+    /// Whether to verify the dedicated `both` solutions against silver and gold instead
+    check_both: bool,
+    compare_baseline: Option<Baseline>,
+    regression_threshold: f64,
+    save_baseline: Option<Baseline>,
+}
+
+impl Default for RunPlan {
+    fn default() -> Self {
+        Self {
+            solve_options: SolveOptions::default(),
+            jobs: 1,
+            format: OutputFormat::Table,
+            summary: false,
+            breakdown: None,
+            bench: false,
+            rows: true,
+            expected_answers: None,
+            check_both: false,
+            compare_baseline: None,
+            regression_threshold: 0.0,
+            save_baseline: None,
+        }
+    }
+}
+
+fn execute(selection: &Selection, mut plan: RunPlan) -> Result<ExitCode, String> {
+    let inputs = match selection.example {
+        Some(number) => {
+            let examples = Examples::load(&selection.inputs.input_dir)?;
+            let inputs = examples.inputs(&selection.puzzles()?, number)?;
+            if !plan.check_both {
+                plan.expected_answers = Some(examples.expected_answers());
+            }
+            inputs
+        }
+        None => selection.puzzle_inputs()?,
+    };
+    let parts = selection.parts();
+    let mut both_check = plan
+        .check_both
+        .then(|| BothCheck::new(inputs.iter().map(|input| input.puzzle)));
+    let mut breakdown = plan
+        .breakdown
+        .map(|top| BreakdownBuilder::new(inputs.iter().map(|input| input.puzzle), top));
+
+    let mut summary = RunSummary::default();
+    let mut timing_totals = TimingTotals::default();
+    let mut memory_totals = MemoryTotals::default();
+    let mut verification = VerificationCounts::default();
+    let mut baseline_summary = BaselineSummary::default();
+    let mut parse_duration_ms = 0.0;
+
+    let parse = inputs.iter().any(|input| input.puzzle.has_parse());
+    let mut reporter = create_reporter(
+        plan.format,
+        ReportOptions {
+            inputs: inputs.iter().any(|input| input.name.is_some()),
+            verify: plan.expected_answers.is_some() || plan.check_both,
+            bench: plan.bench,
+            compare_baseline: plan.compare_baseline.is_some(),
+            memory: memory::ENABLED,
+            parse,
+        },
+    );
+
+    run_puzzles(
+        &inputs,
+        &parts,
+        &plan.solve_options,
+        plan.jobs,
+        |mut result| {
+            summary.total_duration_ms += result.duration_ms;
+            if let Some(parse_ms) = result.parse_ms {
+                summary.total_duration_ms += parse_ms;
+                parse_duration_ms += parse_ms;
+            }
+            if result.timeout_s.is_some() {
+                summary.timeouts += 1;
+            }
+            match &result.puzzle_error {
+                Some(err) if !err.is_failure() => summary.not_implemented += 1,
+                _ if result.error.is_some() || result.puzzle_error.is_some() => summary.errors += 1,
+                _ => {}
+            }
+            if let Some(stats) = &result.timing {
+                timing_totals += stats;
+            }
+            if let Some(stats) = &result.memory {
+                memory_totals += stats;
+            }
+            if let Some(breakdown) = &mut breakdown {
+                breakdown.add(&result);
+            }
+            if let Some(verdict) = plan
+                .expected_answers
+                .as_ref()
+                .and_then(|answers| answers.get(&result.input))
+                .and_then(|answers| answers.verify(&mut result))
+            {
+                verification.add(verdict);
+            }
+            if let Some(verdict) = both_check
+                .as_mut()
+                .and_then(|check| check.verify(&mut result))
+            {
+                verification.add(verdict);
+            }
+            if let Some(baseline) = &plan.compare_baseline {
+                if let Some(delta) = baseline.compare(&mut result, plan.regression_threshold) {
+                    baseline_summary.add(&delta, result.duration_ms);
+                }
+            }
+            if let Some(baseline) = &mut plan.save_baseline {
+                baseline.record(&result);
+            }
+            if plan.rows {
+                reporter.report(&result);
+            }
+        },
+    );
+
+    if plan.bench {
+        summary.timing = Some(timing_totals);
+    }
+    if parse {
+        summary.parse_duration_ms = Some(parse_duration_ms);
+    }
+    if memory::ENABLED {
+        summary.memory = Some(memory_totals);
+    }
+    if plan.expected_answers.is_some() || plan.check_both {
+        summary.verification = Some(verification);
+    }
+    if let Some(baseline) = &plan.compare_baseline {
+        baseline_summary.name = baseline.name().to_string();
+        summary.baseline = Some(baseline_summary);
+    }
+    summary.breakdown = breakdown.map(BreakdownBuilder::finish);
+    // Failures show the summary even without `-s`, as they make the run exit with status 1
+    if plan.summary || summary.errors > 0 || summary.timeouts > 0 {
+        reporter.summary(&summary);
+    }
+    reporter.finish();
+
+    if let Some(baseline) = &plan.save_baseline {
+        baseline.save()?;
+    }
+
+    if verification.fail > 0 || summary.errors > 0 || summary.timeouts > 0 {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -0,0 +1,217 @@
+use crate::aoc_puzzle::{AocPuzzle, PuzzlePart};
+use crate::inputs::InputSet;
+use std::fmt::Write;
+use std::path::Path;
+
+const PARTS: [PuzzlePart; 3] = [PuzzlePart::Silver, PuzzlePart::Gold, PuzzlePart::Both];
+
+/// The state of a single year/day cell in the coverage grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Coverage {
+    /// Every part runs. `dedicated_both` is set when `both` has its own solution.
+    Implemented {
+        dedicated_both: bool,
+    },
+    Skipped,
+    MissingInput,
+    /// Registered with parts that are still stubs, see `AocPuzzle::unsolved`
+    Unsolved,
+    NotImplemented,
+}
+
+impl Coverage {
+    /// A missing input takes precedence over unsolved and skipped parts, since nothing can run
+    /// without it.
+    pub fn of(puzzle: Option<&AocPuzzle>, has_input: bool) -> Self {
+        match puzzle {
+            None => Coverage::NotImplemented,
+            Some(_) if !has_input => Coverage::MissingInput,
+            Some(puzzle) if !puzzle.is_solved() => Coverage::Unsolved,
+            Some(puzzle) if PARTS.iter().any(|&part| puzzle.skip_reason(part).is_some()) => {
+                Coverage::Skipped
+            }
+            Some(puzzle) => Coverage::Implemented {
+                dedicated_both: puzzle.has_both(),
+            },
+        }
+    }
+
+    fn symbol(self) -> char {
+        match self {
+            Coverage::Implemented {
+                dedicated_both: true,
+            } => 'B',
+            Coverage::Implemented {
+                dedicated_both: false,
+            } => '#',
+            Coverage::Skipped => 's',
+            Coverage::MissingInput => '?',
+            Coverage::Unsolved => 'u',
+            Coverage::NotImplemented => '.',
+        }
+    }
+
+    fn color(self) -> Option<u8> {
+        match self {
+            Coverage::Implemented { .. } => Some(32),
+            Coverage::Skipped => Some(33),
+            Coverage::MissingInput => Some(31),
+            Coverage::Unsolved => Some(90),
+            Coverage::NotImplemented => None,
+        }
+    }
+}
+
+/// Renders a year × day grid of the puzzles, followed by a legend, totals and the details of
+/// any skipped parts and missing inputs of the input set.
+pub fn render(
+    puzzles: &[&AocPuzzle],
+    years: &[u16],
+    days: &[u8],
+    input_set: &InputSet,
+    has_input: impl Fn(&Path) -> bool,
+    color: bool,
+) -> String {
+    let mut out = String::from("    ");
+    for day in days {
+        write!(out, " {:>2}", day).unwrap();
+    }
+    out.push('\n');
+
+    let mut skipped = Vec::new();
+    let mut missing_input = Vec::new();
+    let mut complete = 0;
+    let mut unsolved = 0;
+
+    for &year in years {
+        write!(out, "{}", year).unwrap();
+        for &day in days {
+            let puzzle = puzzles
+                .iter()
+                .copied()
+                .find(|p| p.year == year && p.day == day);
+            let input_path = input_set.path(year, day);
+            let coverage = Coverage::of(puzzle, has_input(&input_path));
+            let symbol = coverage.symbol().to_string();
+            let cell = match coverage.color() {
+                Some(code) if color => format!("\x1b[{}m{}\x1b[0m", code, symbol),
+                _ => symbol,
+            };
+            write!(out, "  {}", cell).unwrap();
+
+            let Some(puzzle) = puzzle else {
+                continue;
+            };
+            if coverage == Coverage::MissingInput {
+                missing_input.push(input_path);
+            }
+            for part in PARTS {
+                if let Some(reason) = puzzle.skip_reason(part) {
+                    skipped.push(format!(
+                        "{} day {:>2} {}: {}",
+                        year,
+                        day,
+                        part.as_str(),
+                        reason
+                    ));
+                }
+            }
+            match coverage {
+                Coverage::Implemented { .. } => complete += 1,
+                Coverage::Unsolved => unsolved += 1,
+                _ => {}
+            }
+        }
+        out.push('\n');
+    }
+
+    let shown: Vec<&AocPuzzle> = puzzles
+        .iter()
+        .copied()
+        .filter(|p| years.contains(&p.year) && days.contains(&p.day))
+        .collect();
+    let dedicated_both = shown.iter().filter(|p| p.has_both()).count();
+
+    out.push_str(
+        "\nB = implemented with a dedicated both function, # = implemented, s = parts skipped, \
+         ? = missing input, u = unsolved parts, . = not implemented\n",
+    );
+    writeln!(
+        out,
+        "Puzzles: {} ({} complete, {} with skipped parts, {} missing input, {} with unsolved parts, {} with a dedicated both function)",
+        shown.len(),
+        complete,
+        shown.len() - complete - missing_input.len() - unsolved,
+        missing_input.len(),
+        unsolved,
+        dedicated_both
+    )
+    .unwrap();
+
+    if !skipped.is_empty() {
+        out.push_str("\nSkipped parts:\n");
+        for line in skipped {
+            writeln!(out, "  {}", line).unwrap();
+        }
+    }
+    if !missing_input.is_empty() {
+        out.push_str("\nMissing input:\n");
+        for path in missing_input {
+            writeln!(out, "  {}", path.display()).unwrap();
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle_collection::get_puzzles;
+    use crate::puzzle_error::PuzzleError;
+
+    #[test]
+    fn coverage_of_a_puzzle() {
+        let day_1 = get_puzzles(Some(2020), Some(1))[0];
+        let day_20 = get_puzzles(Some(2020), Some(20))[0];
+        assert_eq!(Coverage::of(None, false), Coverage::NotImplemented);
+        assert_eq!(Coverage::of(Some(day_1), false), Coverage::MissingInput);
+        assert_eq!(Coverage::of(Some(day_20), false), Coverage::MissingInput);
+        assert_eq!(Coverage::of(Some(day_20), true), Coverage::Skipped);
+        assert!(matches!(
+            Coverage::of(Some(day_1), true),
+            Coverage::Implemented { .. }
+        ));
+
+        // A new day is missing its input, and unsolved until its parts are
+        let new_day = AocPuzzle::new(
+            2026,
+            3,
+            |_| Err(PuzzleError::NotImplemented),
+            |_| Err(PuzzleError::NotImplemented),
+        )
+        .unsolved(PuzzlePart::Silver)
+        .unsolved(PuzzlePart::Gold);
+        assert_eq!(Coverage::of(Some(&new_day), false), Coverage::MissingInput);
+        assert_eq!(Coverage::of(Some(&new_day), true), Coverage::Unsolved);
+    }
+
+    #[test]
+    fn render_grid() {
+        let puzzles = get_puzzles(Some(2020), None);
+        let days: Vec<u8> = (1..=25).collect();
+        let input_set = InputSet::new(Path::new("./input"), None).unwrap();
+        let has_input = |path: &Path| path.ends_with("2020_01.txt");
+        let grid = render(&puzzles, &[2020], &days, &input_set, has_input, false);
+        let row = grid.lines().nth(1).unwrap();
+
+        assert!(row.starts_with("2020  "));
+        let cells: Vec<&str> = row.split_whitespace().skip(1).collect();
+        assert_eq!(cells.len(), 25);
+        assert!(cells[0] == "#" || cells[0] == "B");
+        assert_eq!(cells[1], ".");
+        assert_eq!(cells[19], "?");
+        assert!(grid.contains("Puzzles: 2 (1 complete, 0 with skipped parts, 1 missing input"));
+        assert!(grid.contains("2020 day 20 gold: Not implemented in TypeScript"));
+        assert!(grid.contains("./input/2020_20.txt"));
+    }
+}
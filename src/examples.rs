@@ -0,0 +1,185 @@
+use crate::answers::ExpectedAnswers;
+use crate::aoc_puzzle::{AocPuzzle, PuzzlePart};
+use crate::inputs::{PuzzleInput, ANSWERS_FILE};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The subdirectory of the input directory with the examples from the puzzle descriptions.
+pub const EXAMPLES_DIR: &str = "examples";
+
+const PARTS: [PuzzlePart; 3] = [PuzzlePart::Silver, PuzzlePart::Gold, PuzzlePart::Both];
+
+/// Why the parts an example doesn't apply to are skipped.
+pub const NO_ANSWER: &str = "No answer for this example";
+
+/// The examples from the puzzle descriptions, shared by the tests and the `--example` option.
+/// Each example is a file named `YYYY_DD_N.txt`, numbered from 1 per puzzle, and the answers
+/// to each are in `answers.toml` next to them.
+pub struct Examples {
+    dir: PathBuf,
+    answers: HashMap<u32, ExpectedAnswers>,
+}
+
+impl Examples {
+    /// Loads the answers of the examples in `input_dir`. Without an answers file, the examples
+    /// can still be run, but there is nothing to check them against.
+    pub fn load(input_dir: &Path) -> Result<Self, String> {
+        let dir = input_dir.join(EXAMPLES_DIR);
+        let answers_path = dir.join(ANSWERS_FILE);
+        let answers = if answers_path.is_file() {
+            ExpectedAnswers::load_examples(&answers_path)?
+        } else {
+            HashMap::new()
+        };
+        Ok(Self { dir, answers })
+    }
+
+    pub fn path(&self, year: u16, day: u8, number: u32) -> PathBuf {
+        self.dir.join(format!("{}_{:02}_{}.txt", year, day, number))
+    }
+
+    /// The numbers of the examples of a puzzle, in order.
+    pub fn numbers(&self, year: u16, day: u8) -> Vec<u32> {
+        let prefix = format!("{}_{:02}_", year, day);
+        let mut numbers: Vec<u32> = fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                name.strip_prefix(&prefix)?
+                    .strip_suffix(".txt")?
+                    .parse()
+                    .ok()
+            })
+            .collect();
+        numbers.sort_unstable();
+        numbers
+    }
+
+    /// The examples of the puzzles to run, all of them or only the one with the given number.
+    /// Parts without a known answer for an example are skipped, since an example often only
+    /// applies to one of the parts.
+    pub fn inputs(
+        &self,
+        puzzles: &[&'static AocPuzzle],
+        number: Option<u32>,
+    ) -> Result<Vec<PuzzleInput>, String> {
+        let mut inputs = Vec::new();
+        for &puzzle in puzzles {
+            for n in self.numbers(puzzle.year, puzzle.day) {
+                if number.is_some_and(|number| number != n) {
+                    continue;
+                }
+                let skip = PARTS
+                    .into_iter()
+                    .filter(|&part| {
+                        self.answers
+                            .get(&n)
+                            .and_then(|answers| answers.expected(puzzle.year, puzzle.day, part))
+                            .is_none()
+                    })
+                    .map(|part| (part, NO_ANSWER))
+                    .collect();
+                inputs.push(PuzzleInput {
+                    puzzle,
+                    path: self.path(puzzle.year, puzzle.day, n),
+                    name: Some(label(n)),
+                    skip,
+                });
+            }
+        }
+        if inputs.is_empty() {
+            let which = match number {
+                Some(n) => format!("example {}", n),
+                None => "examples".to_string(),
+            };
+            return Err(format!(
+                "No {} found for the selected puzzles in {}",
+                which,
+                self.dir.display()
+            ));
+        }
+        Ok(inputs)
+    }
+
+    /// The answers of the examples, keyed by the input name of each example.
+    pub fn expected_answers(self) -> HashMap<Option<String>, ExpectedAnswers> {
+        self.answers
+            .into_iter()
+            .map(|(number, answers)| (Some(label(number)), answers))
+            .collect()
+    }
+}
+
+fn label(number: u32) -> String {
+    format!("example {}", number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Verdict;
+    use crate::aoc_puzzle::SolveOptions;
+    use crate::inputs::{read_input_file, DEFAULT_INPUT_DIR};
+    use crate::puzzle_collection::get_puzzles;
+    use std::time::Duration;
+
+    #[test]
+    fn example_inputs() {
+        let examples = Examples::load(Path::new(DEFAULT_INPUT_DIR)).unwrap();
+        let day_11 = get_puzzles(Some(2025), Some(11));
+        assert_eq!(examples.numbers(2025, 11), vec![1, 2]);
+
+        let inputs = examples.inputs(&day_11, None).unwrap();
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs[0].path, Path::new("./input/examples/2025_11_1.txt"));
+        assert_eq!(inputs[0].name.as_deref(), Some("example 1"));
+        assert_eq!(
+            inputs[0].skip,
+            vec![(PuzzlePart::Gold, NO_ANSWER), (PuzzlePart::Both, NO_ANSWER)]
+        );
+
+        let inputs = examples.inputs(&day_11, Some(2)).unwrap();
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].name.as_deref(), Some("example 2"));
+        assert!(examples.inputs(&day_11, Some(3)).is_err());
+    }
+
+    // Keeps the answers file in line with the examples: every answer in it must be right
+    #[test]
+    fn examples_match_their_answers() {
+        let examples = Examples::load(Path::new(DEFAULT_INPUT_DIR)).unwrap();
+        // Parts that are too slow to run by default are only slow on the actual inputs
+        let options = SolveOptions {
+            timeout: Some(Duration::from_secs(60)),
+            ..SolveOptions::default()
+        };
+        for puzzle in get_puzzles(None, None) {
+            for n in examples.numbers(puzzle.year, puzzle.day) {
+                let Some(answers) = examples.answers.get(&n) else {
+                    continue;
+                };
+                let input = read_input_file(&examples.path(puzzle.year, puzzle.day, n)).unwrap();
+                for part in PARTS {
+                    let Some(expected) = answers.expected(puzzle.year, puzzle.day, part) else {
+                        continue;
+                    };
+                    let mut result = puzzle.solve_part(part, &input, &options);
+                    assert_eq!(
+                        answers.verify(&mut result),
+                        Some(Verdict::Pass),
+                        "{} day {} example {} {}: {:?} != {:?}",
+                        puzzle.year,
+                        puzzle.day,
+                        n,
+                        part.as_str(),
+                        result.answers,
+                        expected
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::aoc_puzzle::{AocPuzzle, PuzzlePart};
+use crate::examples::EXAMPLES_DIR;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_INPUT_DIR: &str = "./input";
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+pub const ANSWERS_FILE: &str = "answers.toml";
+pub const SUBMISSIONS_FILE: &str = "submissions.json";
+/// The input path that stands for standard input.
+pub const STDIN_PATH: &str = "-";
+
+/// A directory of puzzle inputs named `YYYY_DD.txt`: either the input directory itself, or a
+/// named set for another account in one of its subdirectories (`input/<profile>/2025_01.txt`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputSet {
+    pub profile: Option<String>,
+    pub dir: PathBuf,
+}
+
+impl InputSet {
+    pub fn new(input_dir: &Path, profile: Option<&str>) -> Result<Self, String> {
+        let Some(profile) = profile else {
+            return Ok(Self {
+                profile: None,
+                dir: input_dir.to_path_buf(),
+            });
+        };
+        if profile.is_empty() || profile.starts_with('.') || profile.contains(['/', '\\']) {
+            return Err(format!("Invalid input profile name '{}'", profile));
+        }
+        Ok(Self {
+            profile: Some(profile.to_string()),
+            dir: input_dir.join(profile),
+        })
+    }
+
+    /// The input directory itself, followed by every subdirectory in it, sorted by name. The
+    /// examples directory is not an input set.
+    pub fn discover(input_dir: &Path) -> Result<Vec<Self>, String> {
+        let entries = fs::read_dir(input_dir).map_err(|err| {
+            format!(
+                "Failed to read input directory {}: {}",
+                input_dir.display(),
+                err
+            )
+        })?;
+        let mut profiles: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter(|name| !name.starts_with('.') && name != EXAMPLES_DIR)
+            .collect();
+        profiles.sort();
+
+        let mut sets = vec![Self::new(input_dir, None)?];
+        for profile in profiles {
+            sets.push(Self::new(input_dir, Some(&profile))?);
+        }
+        Ok(sets)
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir.join(format!("{}_{:02}.txt", year, day))
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        self.dir.join(ANSWERS_FILE)
+    }
+
+    /// The ledger of the answers submitted for the inputs in this set.
+    pub fn submissions_path(&self) -> PathBuf {
+        self.dir.join(SUBMISSIONS_FILE)
+    }
+}
+
+/// A puzzle together with the input file to solve it with.
+#[derive(Clone)]
+pub struct PuzzleInput {
+    pub puzzle: &'static AocPuzzle,
+    pub path: PathBuf,
+    /// The input set or example the file belongs to, when it isn't the default input.
+    pub name: Option<String>,
+    /// Parts not to run on this input, with the reason.
+    pub skip: Vec<(PuzzlePart, &'static str)>,
+}
+
+impl PuzzleInput {
+    pub fn from_set(puzzle: &'static AocPuzzle, set: &InputSet) -> Self {
+        Self {
+            puzzle,
+            path: set.path(puzzle.year, puzzle.day),
+            name: set.profile.clone(),
+            skip: Vec::new(),
+        }
+    }
+}
+
+/// Reads an input file, or standard input when the path is `-`. Trailing whitespace is removed,
+/// since editors and downloads don't agree on whether the file ends with a newline.
+pub fn read_input_file(path: &Path) -> Result<String, String> {
+    let input = if path == Path::new(STDIN_PATH) {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map(|_| input)
+            .map_err(|err| format!("Failed to read input from stdin: {}", err))?
+    } else {
+        fs::read_to_string(path)
+            .map_err(|_| format!("Failed to read input file: {}", path.display()))?
+    };
+    Ok(input.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn input_set_paths() {
+        let default = InputSet::new(Path::new("./input"), None).unwrap();
+        assert_eq!(default.path(2025, 1), PathBuf::from("./input/2025_01.txt"));
+
+        let alice = InputSet::new(Path::new("./input"), Some("alice")).unwrap();
+        assert_eq!(
+            alice.path(2025, 1),
+            PathBuf::from("./input/alice/2025_01.txt")
+        );
+        assert_eq!(
+            alice.answers_path(),
+            PathBuf::from("./input/alice/answers.toml")
+        );
+        assert_eq!(
+            alice.submissions_path(),
+            PathBuf::from("./input/alice/submissions.json")
+        );
+
+        assert!(InputSet::new(Path::new("./input"), Some("../bob")).is_err());
+        assert!(InputSet::new(Path::new("./input"), Some(".hidden")).is_err());
+    }
+
+    #[test]
+    fn discover_input_sets() {
+        let dir = env::temp_dir().join(format!("aoc_inputs_test_{}", std::process::id()));
+        fs::create_dir_all(dir.join("bob")).unwrap();
+        fs::create_dir_all(dir.join("alice")).unwrap();
+        fs::create_dir_all(dir.join(".git")).unwrap();
+        fs::create_dir_all(dir.join(EXAMPLES_DIR)).unwrap();
+        fs::write(dir.join("2025_01.txt"), "1").unwrap();
+
+        let profiles: Vec<Option<String>> = InputSet::discover(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|set| set.profile)
+            .collect();
+        assert_eq!(
+            profiles,
+            vec![None, Some("alice".to_string()), Some("bob".to_string())]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(InputSet::discover(&dir).is_err());
+    }
+}
@@ -0,0 +1,54 @@
+//! Advent of Code solutions and the tools to run, verify and benchmark them.
+//!
+//! Each solution is in `yYYYY::day_DD`, with public `silver` and `gold` functions (and `both`
+//! for the days that solve both parts at once) and a `puzzle()` that registers them. The `aoc`
+//! binary is a thin command line on top of [`commands::dispatch`].
+//!
+//! ```
+//! use aoc::{get_puzzles, PuzzlePart};
+//!
+//! let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+//! assert_eq!(aoc::y2025::day_01::silver(input).unwrap().to_string(), "3");
+//!
+//! let puzzle = get_puzzles(Some(2025), Some(1))[0];
+//! let answers = puzzle.run_part(PuzzlePart::Both, input).unwrap();
+//! assert_eq!(answers[1].to_string(), "6");
+//! ```
+
+pub mod answer;
+mod answers;
+mod aoc_client;
+pub mod aoc_puzzle;
+mod baseline;
+mod breakdown;
+pub mod cli;
+pub mod commands;
+mod coverage;
+mod examples;
+mod inputs;
+mod memory;
+mod panics;
+pub mod puzzle_collection;
+pub mod puzzle_error;
+mod reporter;
+mod runner;
+mod scaffold;
+mod stats;
+mod submissions;
+#[cfg(test)]
+mod test_support;
+mod watch;
+
+include!(concat!(env!("OUT_DIR"), "/puzzle_modules.rs"));
+
+pub use aoc_puzzle::{AocPuzzle, PuzzlePart};
+pub use puzzle_collection::get_puzzles;
+// The types of the `PartResult` fields, from modules that are otherwise internal
+pub use answers::Verdict;
+pub use baseline::BaselineDelta;
+pub use memory::MemoryStats;
+pub use stats::TimingStats;
+// The types `cli` and `commands` take and return, from modules that are otherwise internal
+pub use aoc_client::ClientConfig;
+pub use inputs::{InputSet, PuzzleInput};
+pub use reporter::OutputFormat;
@@ -0,0 +1,161 @@
+//! Heap profiling of the solutions. With the `alloc-stats` feature, a global allocator wraps
+//! the system one and counts the allocations of each thread, so that parts running at the
+//! same time don't count each other's. Allocations made by threads that a solution spawns
+//! itself are not counted.
+
+use serde::Serialize;
+use std::ops::AddAssign;
+
+/// Whether the counting allocator is installed, so that `measure` has anything to report.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// The heap use of one run of a part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct MemoryStats {
+    /// The most the part had allocated at once, on top of what was allocated before it started
+    pub peak_bytes: usize,
+    pub allocations: u64,
+}
+
+/// The highest peak and the total number of allocations across a whole run.
+#[derive(Clone, Copy, Default, Serialize)]
+pub struct MemoryTotals {
+    pub peak_bytes: usize,
+    pub allocations: u64,
+}
+
+impl AddAssign<&MemoryStats> for MemoryTotals {
+    fn add_assign(&mut self, stats: &MemoryStats) {
+        self.peak_bytes = self.peak_bytes.max(stats.peak_bytes);
+        self.allocations += stats.allocations;
+    }
+}
+
+/// Runs `f` and measures what it allocates on the current thread, or only runs it when the
+/// counting allocator isn't installed.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (value, stats) = counting::measure(f);
+        (value, Some(stats))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::MemoryStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    // Constant-initialized without a destructor, so the allocator can use them at any point in
+    // the life of a thread without allocating itself. Memory freed on another thread than the
+    // one that allocated it can take `CURRENT` below zero.
+    thread_local! {
+        static CURRENT: Cell<isize> = const { Cell::new(0) };
+        static PEAK: Cell<isize> = const { Cell::new(0) };
+        static COUNT: Cell<u64> = const { Cell::new(0) };
+    }
+
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn record(allocated: isize, freed: isize) {
+        let current = CURRENT.get() + allocated - freed;
+        CURRENT.set(current);
+        PEAK.set(PEAK.get().max(current));
+        if allocated > 0 {
+            COUNT.set(COUNT.get() + 1);
+        }
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(layout.size() as isize, 0);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(layout.size() as isize, 0);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(0, layout.size() as isize);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record(new_size as isize, layout.size() as isize);
+            }
+            new_ptr
+        }
+    }
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, MemoryStats) {
+        let start = CURRENT.get();
+        let count = COUNT.get();
+        PEAK.set(start);
+
+        let value = f();
+
+        let stats = MemoryStats {
+            peak_bytes: (PEAK.get() - start).max(0) as usize,
+            allocations: COUNT.get() - count,
+        };
+        (value, stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_allocations() {
+        let (len, stats) = measure(|| {
+            let first = vec![0u8; 4096];
+            drop(first);
+            let mut second = Vec::with_capacity(1024);
+            second.extend_from_slice(&[1u8; 1024]);
+            second.len()
+        });
+        assert_eq!(len, 1024);
+        assert_eq!(stats.is_some(), ENABLED);
+        if let Some(stats) = stats {
+            assert_eq!(
+                stats,
+                MemoryStats {
+                    peak_bytes: 4096,
+                    allocations: 2
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn totals_keep_the_highest_peak() {
+        let mut totals = MemoryTotals::default();
+        for (peak_bytes, allocations) in [(100, 3), (400, 1), (200, 5)] {
+            totals += &MemoryStats {
+                peak_bytes,
+                allocations,
+            };
+        }
+        assert_eq!(totals.peak_bytes, 400);
+        assert_eq!(totals.allocations, 9);
+    }
+}
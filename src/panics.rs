@@ -0,0 +1,63 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+
+thread_local! {
+    static IN_SOLUTION: Cell<bool> = const { Cell::new(false) };
+    static LAST_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Keeps panics raised inside solutions off stderr, since they are reported as part results
+/// instead. Panics anywhere else still go through the default hook.
+pub fn install_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if IN_SOLUTION.with(Cell::get) {
+            let location = info.location().map(|l| l.to_string());
+            LAST_LOCATION.with(|last| *last.borrow_mut() = location);
+        } else {
+            default_hook(info);
+        }
+    }));
+}
+
+/// Runs a solution, turning a panic into an error message that includes where it happened.
+pub fn catch_solution_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    IN_SOLUTION.with(|flag| flag.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    IN_SOLUTION.with(|flag| flag.set(false));
+
+    result.map_err(|payload| {
+        let message = panic_message(payload.as_ref());
+        match LAST_LOCATION.with(|last| last.borrow_mut().take()) {
+            Some(location) => format!("{} ({})", message, location),
+            None => message,
+        }
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catch_panic_message() {
+        let result = catch_solution_panic(|| -> u32 { panic!("Invalid parameter mode: {}", 7) });
+        assert!(result.unwrap_err().starts_with("Invalid parameter mode: 7"));
+    }
+
+    #[test]
+    fn catch_no_panic() {
+        assert_eq!(catch_solution_panic(|| 42), Ok(42));
+    }
+}
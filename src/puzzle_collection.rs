@@ -1,38 +1,49 @@
 use crate::aoc_puzzle::AocPuzzle;
-use crate::{y2015, y2016, y2017, y2018, y2019, y2020, y2021, y2022, y2023, y2024, y2025};
+use std::sync::LazyLock;
+
+type PuzzleFn = fn() -> AocPuzzle;
+
+/// Every `src/yYYYY/day_DD` module found by the build script, in year and day order.
+const REGISTRY: &[(u16, u8, PuzzleFn)] = include!(concat!(env!("OUT_DIR"), "/puzzle_registry.rs"));
+
+static PUZZLES: LazyLock<Vec<AocPuzzle>> = LazyLock::new(|| {
+    REGISTRY
+        .iter()
+        .map(|&(year, day, puzzle_fn)| {
+            let puzzle = puzzle_fn();
+            assert!(
+                puzzle.year == year && puzzle.day == day,
+                "Module for {} day {} defines a puzzle for {} day {}",
+                year,
+                day,
+                puzzle.year,
+                puzzle.day
+            );
+            puzzle
+        })
+        .collect()
+});
 
 pub fn get_puzzles(year: Option<u16>, day: Option<u8>) -> Vec<&'static AocPuzzle> {
-    let iter: Box<dyn Iterator<Item = &'static AocPuzzle>> = match year {
-        Some(2015) => Box::new(y2015::puzzles().iter()),
-        Some(2016) => Box::new(y2016::puzzles().iter()),
-        Some(2017) => Box::new(y2017::puzzles().iter()),
-        Some(2018) => Box::new(y2018::puzzles().iter()),
-        Some(2019) => Box::new(y2019::puzzles().iter()),
-        Some(2020) => Box::new(y2020::puzzles().iter()),
-        Some(2021) => Box::new(y2021::puzzles().iter()),
-        Some(2022) => Box::new(y2022::puzzles().iter()),
-        Some(2023) => Box::new(y2023::puzzles().iter()),
-        Some(2024) => Box::new(y2024::puzzles().iter()),
-        Some(2025) => Box::new(y2025::puzzles().iter()),
-        Some(_) => return Vec::new(),
-        None => Box::new(
-            y2015::puzzles()
-                .iter()
-                .chain(y2016::puzzles().iter())
-                .chain(y2017::puzzles().iter())
-                .chain(y2018::puzzles().iter())
-                .chain(y2019::puzzles().iter())
-                .chain(y2020::puzzles().iter())
-                .chain(y2021::puzzles().iter())
-                .chain(y2022::puzzles().iter())
-                .chain(y2023::puzzles().iter())
-                .chain(y2024::puzzles().iter())
-                .chain(y2025::puzzles().iter()),
-        ),
-    };
+    PUZZLES
+        .iter()
+        .filter(|p| year.is_none_or(|y| p.year == y) && day.is_none_or(|d| p.day == d))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registered_puzzles_match_their_modules() {
+        assert_eq!(get_puzzles(None, None).len(), REGISTRY.len());
+    }
 
-    match day {
-        Some(d) => iter.filter(|p| p.day == d).collect(),
-        None => iter.collect(),
+    #[test]
+    fn filter_puzzles() {
+        assert_eq!(get_puzzles(Some(2020), None).len(), 2);
+        assert_eq!(get_puzzles(Some(2021), Some(13)).len(), 1);
+        assert!(get_puzzles(Some(2014), None).is_empty());
     }
 }
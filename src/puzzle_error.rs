@@ -0,0 +1,92 @@
+use serde::Serialize;
+use std::error::Error;
+use std::fmt;
+
+/// Why a solution could not produce an answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PuzzleError {
+    /// The input doesn't have the expected format. Line and column are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input was understood, but searching it didn't turn up an answer.
+    NoSolution,
+    NotImplemented,
+}
+
+impl PuzzleError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        PuzzleError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// A parse error located at `token`, which must be a slice of `input`.
+    pub fn parse_at(input: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + token.len() <= input.len())
+            .expect("token is not part of the input");
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+        Self::parse(line, column, message)
+    }
+
+    /// Whether the solution failed, as opposed to the part not being implemented at all.
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, PuzzleError::NotImplemented)
+    }
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PuzzleError::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "parse error at line {}, column {}: {}",
+                line, column, message
+            ),
+            PuzzleError::NoSolution => write!(f, "no solution found"),
+            PuzzleError::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_position() {
+        let input = "1,2,3\n4,x,6";
+        let token = input.split(['\n', ',']).nth(4).unwrap();
+        assert_eq!(
+            PuzzleError::parse_at(input, token, "invalid number"),
+            PuzzleError::parse(2, 3, "invalid number")
+        );
+        assert_eq!(
+            PuzzleError::parse_at(input, &input[..1], "first").to_string(),
+            "parse error at line 1, column 1: first"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "token is not part of the input")]
+    fn parse_error_outside_input() {
+        let token = String::from("x");
+        PuzzleError::parse_at("1,2,3", &token, "invalid number");
+    }
+}
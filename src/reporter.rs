@@ -0,0 +1,610 @@
+use crate::answer::Answer;
+use crate::answers::{Verdict, VerificationCounts};
+use crate::aoc_puzzle::PartResult;
+use crate::baseline::BaselineSummary;
+use crate::breakdown::TimingBreakdown;
+use crate::memory::MemoryTotals;
+use crate::puzzle_error::PuzzleError;
+use crate::stats::TimingTotals;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::io::{stdout, IsTerminal};
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+/// Which optional columns the reporters should include.
+#[derive(Clone, Copy, Default)]
+pub struct ReportOptions {
+    /// Whether results come from more than one input set, and need an input column
+    pub inputs: bool,
+    pub verify: bool,
+    pub bench: bool,
+    pub compare_baseline: bool,
+    /// Whether the heap use of each part is measured, with the `alloc-stats` feature
+    pub memory: bool,
+    /// Whether any of the puzzles has a parse stage, timed apart from its parts
+    pub parse: bool,
+}
+
+#[derive(Clone, Default, Serialize)]
+pub struct RunSummary {
+    /// The time of every part, and of every input parsed for the puzzles with a parse stage
+    pub total_duration_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_duration_ms: Option<f64>,
+    pub timeouts: usize,
+    /// Parts that panicked, couldn't read their input or returned an error other than
+    /// not implemented
+    pub errors: usize,
+    pub not_implemented: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<TimingTotals>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<VerificationCounts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<BaselineSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryTotals>,
+    /// The timing report asked for with `--summary`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<TimingBreakdown>,
+}
+
+pub trait Reporter {
+    fn report(&mut self, result: &PartResult);
+
+    fn summary(&mut self, summary: &RunSummary);
+
+    fn finish(&mut self) {}
+}
+
+pub fn create_reporter(format: OutputFormat, options: ReportOptions) -> Box<dyn Reporter> {
+    match format {
+        OutputFormat::Table => Box::new(TableReporter {
+            options,
+            color: stdout().is_terminal(),
+            header_written: false,
+        }),
+        OutputFormat::Json => Box::new(JsonReporter::default()),
+        OutputFormat::Csv => Box::new(CsvReporter {
+            options,
+            header_written: false,
+        }),
+    }
+}
+
+pub struct TableReporter {
+    options: ReportOptions,
+    color: bool,
+    header_written: bool,
+}
+
+impl TableReporter {
+    fn paint(&self, code: u8, text: &str) -> String {
+        if self.color {
+            format!("\x1b[{}m{}\x1b[0m", code, text)
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn puzzle_error_cell(&self, error: &PuzzleError) -> String {
+        match error {
+            PuzzleError::Parse {
+                line,
+                column,
+                message,
+            } => self.paint(
+                31,
+                &format!(
+                    "PARSE ERROR at line {}, column {}: {}",
+                    line, column, message
+                ),
+            ),
+            PuzzleError::NoSolution => self.paint(33, "NO SOLUTION FOUND"),
+            PuzzleError::NotImplemented => self.paint(90, "NOT IMPLEMENTED"),
+        }
+    }
+
+    fn verdict_color(verdict: Verdict) -> u8 {
+        match verdict {
+            Verdict::Pass => 32,
+            Verdict::Fail => 31,
+            Verdict::Missing => 33,
+        }
+    }
+}
+
+impl Reporter for TableReporter {
+    fn report(&mut self, result: &PartResult) {
+        // The plain table is self-explanatory, but the extra benchmark and memory columns need
+        // labels. Parse times only get a column of their own next to those, so that a plain
+        // run keeps its layout, and are otherwise left to the summary.
+        let labelled = self.options.bench || self.options.memory;
+        let parse_column = labelled && self.options.parse;
+        if labelled && !self.header_written {
+            let mut header = format!("{:<4} | {:>2} | {:<7} | ", "", "", "");
+            if self.options.inputs {
+                header += &format!("{:<10} | ", "input");
+            }
+            if self.options.bench {
+                header += &format!("{:>10} | ", "median");
+                for label in ["min", "mean", "p95", "stddev"] {
+                    header += &format!("{:>10} | ", label);
+                }
+            } else {
+                header += &format!("{:>10} | ", "time");
+            }
+            if parse_column {
+                header += &format!("{:>10} | ", "parse");
+            }
+            if self.options.memory {
+                header += &format!("{:>10} | {:>10} | ", "peak heap", "allocs");
+            }
+            if self.options.compare_baseline {
+                header += &format!("{:>20} | ", "vs baseline");
+            }
+            if self.options.verify {
+                header += &format!("{:<7} | ", "verdict");
+            }
+            println!("{}answer", header);
+            self.header_written = true;
+        }
+
+        let duration_str = if result.duration_ms > 0.0 {
+            format!("{:.1} ms", result.duration_ms)
+        } else {
+            "--".to_string()
+        };
+
+        let mut row = format!(
+            "{} | {:>2} | {:<7} | ",
+            result.year,
+            result.day,
+            result.part.as_str()
+        );
+        if self.options.inputs {
+            row += &format!("{:<10} | ", result.input.as_deref().unwrap_or("default"));
+        }
+        row += &format!("{:>10} | ", duration_str);
+        if self.options.bench {
+            let stats = result.timing.unwrap_or_default();
+            for value in [stats.min_ms, stats.mean_ms, stats.p95_ms, stats.stddev_ms] {
+                let value_str = if stats.runs > 0 {
+                    format!("{:.1} ms", value)
+                } else {
+                    "--".to_string()
+                };
+                row += &format!("{:>10} | ", value_str);
+            }
+        }
+        if parse_column {
+            let parse_str = match result.parse_ms {
+                Some(parse_ms) => format!("{:.1} ms", parse_ms),
+                None => "--".to_string(),
+            };
+            row += &format!("{:>10} | ", parse_str);
+        }
+        if self.options.memory {
+            let (peak, allocations) = match result.memory {
+                Some(memory) => (
+                    format_bytes(memory.peak_bytes),
+                    memory.allocations.to_string(),
+                ),
+                None => ("--".to_string(), "--".to_string()),
+            };
+            row += &format!("{:>10} | {:>10} | ", peak, allocations);
+        }
+        let mut indent = row.len();
+
+        if self.options.compare_baseline {
+            let delta_str = match result.baseline {
+                Some(delta) => format!(
+                    "{:+.1} ms ({:+.1}%){}",
+                    delta.delta_ms,
+                    delta.delta_pct,
+                    if delta.regressed { "!" } else { " " }
+                ),
+                None => "--".to_string(),
+            };
+            let delta_str = format!("{:>20}", delta_str);
+            indent += delta_str.len() + 3;
+            match result.baseline {
+                Some(delta) if delta.regressed => row += &self.paint(31, &delta_str),
+                _ => row += &delta_str,
+            }
+            row += " | ";
+        }
+
+        if self.options.verify {
+            let verdict_str = format!("{:<7}", result.verdict.map_or("", |v| v.as_str()));
+            indent += verdict_str.len() + 3;
+            match result.verdict {
+                Some(verdict) => row += &self.paint(Self::verdict_color(verdict), &verdict_str),
+                None => row += &verdict_str,
+            }
+            row += " | ";
+        }
+
+        let result_value = if let Some(skip_reason) = result.skipped {
+            skip_reason.to_string()
+        } else if let Some(error) = &result.error {
+            format!("ERROR: {}", error)
+        } else if let Some(error) = &result.puzzle_error {
+            self.puzzle_error_cell(error)
+        } else if let Some(timeout_s) = result.timeout_s {
+            timeout_text(timeout_s)
+        } else if result.answers.iter().all(Answer::is_empty) {
+            "Not solved".to_string()
+        } else {
+            let expected = result.expected.as_deref().unwrap_or_default();
+            let mut lines = Vec::new();
+            for (i, answer) in result.answers.iter().enumerate() {
+                lines.push(match expected.get(i) {
+                    Some(e) if !answer.matches(e) => format!("{} (expected {})", answer, e),
+                    _ => answer.to_string(),
+                });
+                lines.extend(answer.picture_lines().iter().map(|line| line.to_string()));
+            }
+            lines.join(&format!("\n{:>indent$}", ""))
+        };
+
+        println!("{}{}", row, result_value);
+    }
+
+    fn summary(&mut self, summary: &RunSummary) {
+        println!("----------------------------------------------------");
+        for line in summary_lines(summary) {
+            println!("{}", line);
+        }
+    }
+}
+
+#[derive(Default, Serialize)]
+pub struct JsonReporter {
+    results: Vec<PartResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<RunSummary>,
+}
+
+impl Reporter for JsonReporter {
+    fn report(&mut self, result: &PartResult) {
+        self.results.push(result.clone());
+    }
+
+    fn summary(&mut self, summary: &RunSummary) {
+        self.summary = Some(summary.clone());
+    }
+
+    fn finish(&mut self) {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(self).expect("Failed to serialize results")
+        );
+    }
+}
+
+pub struct CsvReporter {
+    options: ReportOptions,
+    header_written: bool,
+}
+
+impl Reporter for CsvReporter {
+    fn report(&mut self, result: &PartResult) {
+        if !self.header_written {
+            let mut header =
+                "year,day,part,duration_ms,answer,skipped,timeout_s,error,puzzle_error".to_string();
+            if self.options.bench {
+                header += ",runs,min_ms,median_ms,mean_ms,p95_ms,stddev_ms";
+            }
+            if self.options.compare_baseline {
+                header += ",baseline_ms,delta_ms,delta_pct,regressed";
+            }
+            if self.options.verify {
+                header += ",verdict,expected";
+            }
+            if self.options.inputs {
+                header += ",input";
+            }
+            if self.options.memory {
+                header += ",peak_bytes,allocations";
+            }
+            if self.options.parse {
+                header += ",parse_ms";
+            }
+            println!("{}", header);
+            self.header_written = true;
+        }
+
+        let mut row = format!(
+            "{},{},{},{:.3},{},{},{},{},{}",
+            result.year,
+            result.day,
+            result.part.as_str(),
+            result.duration_ms,
+            csv_field(
+                &result
+                    .answers
+                    .iter()
+                    .map(Answer::to_string)
+                    .collect::<Vec<_>>()
+                    .join(";")
+            ),
+            csv_field(result.skipped.unwrap_or("")),
+            result.timeout_s.map_or(String::new(), |t| t.to_string()),
+            csv_field(result.error.as_deref().unwrap_or("")),
+            csv_field(
+                &result
+                    .puzzle_error
+                    .as_ref()
+                    .map_or(String::new(), |err| err.to_string())
+            )
+        );
+        if self.options.bench {
+            let stats = result.timing.unwrap_or_default();
+            row += &format!(
+                ",{},{:.3},{:.3},{:.3},{:.3},{:.3}",
+                stats.runs,
+                stats.min_ms,
+                stats.median_ms,
+                stats.mean_ms,
+                stats.p95_ms,
+                stats.stddev_ms
+            );
+        }
+        if self.options.compare_baseline {
+            row += &match result.baseline {
+                Some(delta) => format!(
+                    ",{:.3},{:.3},{:.1},{}",
+                    delta.baseline_ms, delta.delta_ms, delta.delta_pct, delta.regressed
+                ),
+                None => ",,,,".to_string(),
+            };
+        }
+        if self.options.verify {
+            row += &format!(
+                ",{},{}",
+                result.verdict.map_or("", |v| v.as_str()),
+                csv_field(&result.expected.as_deref().unwrap_or_default().join(";"))
+            );
+        }
+        if self.options.inputs {
+            row += &format!(
+                ",{}",
+                csv_field(result.input.as_deref().unwrap_or("default"))
+            );
+        }
+        if self.options.memory {
+            row += &match result.memory {
+                Some(memory) => format!(",{},{}", memory.peak_bytes, memory.allocations),
+                None => ",,".to_string(),
+            };
+        }
+        if self.options.parse {
+            row += &format!(
+                ",{}",
+                result
+                    .parse_ms
+                    .map_or(String::new(), |ms| format!("{:.3}", ms))
+            );
+        }
+        println!("{}", row);
+    }
+
+    // Keep stdout a plain table of rows so it can be piped straight into other tools.
+    fn summary(&mut self, summary: &RunSummary) {
+        for line in summary_lines(summary) {
+            eprintln!("{}", line);
+        }
+    }
+}
+
+fn summary_lines(summary: &RunSummary) -> Vec<String> {
+    let mut lines = vec![match summary.parse_duration_ms {
+        Some(parse_ms) => format!(
+            "Total duration: {:.1} ms (parsing {:.1} ms)",
+            summary.total_duration_ms, parse_ms
+        ),
+        None => format!("Total duration: {:.1} ms", summary.total_duration_ms),
+    }];
+    if summary.errors > 0 {
+        lines.push(format!("Errors: {} parts", summary.errors));
+    }
+    if summary.not_implemented > 0 {
+        lines.push(format!(
+            "Not implemented: {} parts",
+            summary.not_implemented
+        ));
+    }
+    if summary.timeouts > 0 {
+        lines.push(format!("Timed out: {} parts", summary.timeouts));
+    }
+    if let Some(totals) = summary.timing {
+        lines.push(format!(
+            "Total min / median / mean / p95: {:.1} / {:.1} / {:.1} / {:.1} ms",
+            totals.min_ms, totals.median_ms, totals.mean_ms, totals.p95_ms
+        ));
+    }
+    if let Some(memory) = summary.memory {
+        lines.push(format!(
+            "Peak heap: {} in a single part, {} allocations in total",
+            format_bytes(memory.peak_bytes),
+            memory.allocations
+        ));
+    }
+    if let Some(baseline) = &summary.baseline {
+        let delta_ms = baseline.current_ms - baseline.baseline_ms;
+        let delta_pct = if baseline.baseline_ms > 0.0 {
+            delta_ms / baseline.baseline_ms * 100.0
+        } else {
+            0.0
+        };
+        lines.push(format!(
+            "Baseline '{}': {:.1} ms -> {:.1} ms ({:+.1} ms, {:+.1}%) over {} parts, {} regressed",
+            baseline.name,
+            baseline.baseline_ms,
+            baseline.current_ms,
+            delta_ms,
+            delta_pct,
+            baseline.compared,
+            baseline.regressed
+        ));
+    }
+    if let Some(counts) = summary.verification {
+        lines.push(format!(
+            "Verification: {} passed, {} failed, {} missing",
+            counts.pass, counts.fail, counts.missing
+        ));
+    }
+    if let Some(breakdown) = &summary.breakdown {
+        lines.extend(breakdown_lines(breakdown));
+    }
+    lines
+}
+
+fn breakdown_lines(breakdown: &TimingBreakdown) -> Vec<String> {
+    let mut lines = Vec::new();
+    if !breakdown.years.is_empty() {
+        lines.push("By year:".to_string());
+        for total in &breakdown.years {
+            lines.push(format!(
+                "  {}    {:>10.1} ms over {} parts",
+                total.year, total.duration_ms, total.parts
+            ));
+        }
+        lines.push("By part:".to_string());
+        for total in &breakdown.parts {
+            lines.push(format!(
+                "  {:<7} {:>10.1} ms over {} parts",
+                total.part.as_str(),
+                total.duration_ms,
+                total.parts
+            ));
+        }
+    }
+    if !breakdown.slowest.is_empty() {
+        lines.push("Slowest parts:".to_string());
+        for part in &breakdown.slowest {
+            let input = part
+                .input
+                .as_ref()
+                .map_or(String::new(), |input| format!(" ({})", input));
+            lines.push(format!(
+                "  {} day {:>2} {:<7} {:>10.1} ms{}",
+                part.year,
+                part.day,
+                part.part.as_str(),
+                part.duration_ms,
+                input
+            ));
+        }
+    }
+    if !breakdown.both_savings.is_empty() {
+        lines.push("Dedicated both against silver + gold:".to_string());
+        for savings in &breakdown.both_savings {
+            let input = savings
+                .input
+                .as_ref()
+                .map_or(String::new(), |input| format!(" ({})", input));
+            let saved_pct = if savings.separate_ms > 0.0 {
+                savings.saved_ms / savings.separate_ms * 100.0
+            } else {
+                0.0
+            };
+            lines.push(format!(
+                "  {} day {:>2}{}: {:.1} ms against {:.1} ms, {} {:.1} ms ({:.1}%)",
+                savings.year,
+                savings.day,
+                input,
+                savings.both_ms,
+                savings.separate_ms,
+                if savings.saved_ms >= 0.0 {
+                    "saving"
+                } else {
+                    "losing"
+                },
+                savings.saved_ms.abs(),
+                saved_pct.abs()
+            ));
+        }
+    }
+    if !breakdown.skipped.is_empty() {
+        let total: usize = breakdown.skipped.iter().map(|count| count.parts).sum();
+        lines.push(format!("Skipped: {} parts", total));
+        for count in &breakdown.skipped {
+            lines.push(format!("  {:>4}  {}", count.parts, count.reason));
+        }
+    }
+    lines
+}
+
+/// What the table and watch mode show for a part that timed out. Timeouts under a second are
+/// shown in milliseconds, which `--timeout 50ms` would otherwise round away.
+pub fn timeout_text(timeout_s: f64) -> String {
+    if timeout_s < 1.0 {
+        format!("TIMEOUT after {:.0} ms", timeout_s * 1000.0)
+    } else {
+        format!("TIMEOUT after {:.1} s", timeout_s)
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_field_plain() {
+        assert_eq!(csv_field("6099"), "6099");
+    }
+
+    #[test]
+    fn csv_field_quoted() {
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn format_timeouts() {
+        assert_eq!(timeout_text(0.05), "TIMEOUT after 50 ms");
+        assert_eq!(timeout_text(0.5), "TIMEOUT after 500 ms");
+        assert_eq!(timeout_text(10.0), "TIMEOUT after 10.0 s");
+        assert_eq!(timeout_text(1.25), "TIMEOUT after 1.2 s");
+    }
+
+    #[test]
+    fn format_byte_sizes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(12 * 1024 * 1024 + 300 * 1024), "12.3 MiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+    }
+}
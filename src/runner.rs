@@ -0,0 +1,105 @@
+use crate::aoc_puzzle::{PartResult, PuzzlePart, SolveOptions};
+use crate::inputs::{read_input_file, PuzzleInput};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Solves the selected parts of every puzzle input, handing each result to `on_result` in
+/// the order of `inputs` regardless of how many puzzles run concurrently.
+///
+/// With `jobs == 1` everything runs on the calling thread and results are reported as soon as
+/// they are available. With more jobs, whole puzzle inputs are spread over a pool of worker
+/// threads.
+pub fn run_puzzles<F>(
+    inputs: &[PuzzleInput],
+    parts: &[PuzzlePart],
+    options: &SolveOptions,
+    jobs: usize,
+    mut on_result: F,
+) where
+    F: FnMut(PartResult),
+{
+    if jobs <= 1 || inputs.len() <= 1 {
+        for input in inputs {
+            solve_puzzle(input, parts, options, &mut on_result);
+        }
+        return;
+    }
+
+    let next_puzzle = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(inputs.len()) {
+            let sender = sender.clone();
+            let next_puzzle = &next_puzzle;
+            scope.spawn(move || loop {
+                let index = next_puzzle.fetch_add(1, Ordering::Relaxed);
+                let Some(input) = inputs.get(index) else {
+                    break;
+                };
+                let mut results = Vec::with_capacity(parts.len());
+                solve_puzzle(input, parts, options, |result| results.push(result));
+                if sender.send((index, results)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // Buffer results that finish out of order until every earlier puzzle has been reported
+        let mut pending = BTreeMap::new();
+        let mut next_to_report = 0;
+        for (index, results) in receiver {
+            pending.insert(index, results);
+            while let Some(results) = pending.remove(&next_to_report) {
+                results.into_iter().for_each(&mut on_result);
+                next_to_report += 1;
+            }
+        }
+    });
+}
+
+/// Solves the selected parts of a single puzzle, parsing the input once for a puzzle with a
+/// parse stage. If the input can't be read, every part that isn't skipped is reported as
+/// failed with the read error.
+fn solve_puzzle<F>(
+    input: &PuzzleInput,
+    parts: &[PuzzlePart],
+    options: &SolveOptions,
+    mut on_result: F,
+) where
+    F: FnMut(PartResult),
+{
+    let puzzle = input.puzzle;
+    let content = read_input_file(&input.path);
+    let mut solver = content
+        .as_ref()
+        .map(|content| puzzle.solver(content, options));
+    for &part in parts {
+        let skip_reason = input
+            .skip
+            .iter()
+            .find(|(skipped, _)| *skipped == part)
+            .map(|&(_, reason)| reason);
+        let mut result = match &mut solver {
+            _ if skip_reason.is_some() => {
+                let mut result = PartResult::new(puzzle.year, puzzle.day, part);
+                result.skipped = skip_reason;
+                result
+            }
+            Ok(solver) => solver.solve(part),
+            Err(err) => {
+                let mut result = PartResult::new(puzzle.year, puzzle.day, part);
+                result.skipped = puzzle.run_skip_reason(part, options);
+                if result.skipped.is_none() {
+                    result.error = Some(err.to_string());
+                }
+                result
+            }
+        };
+        result.input = input.name.clone();
+        on_result(result);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::inputs::InputSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = r#"use crate::aoc_puzzle::{AocPuzzle, PuzzlePart, PuzzleResult};
+use crate::puzzle_error::PuzzleError;
+
+pub fn silver(_input: &str) -> PuzzleResult {
+    Err(PuzzleError::NotImplemented)
+}
+
+pub fn gold(_input: &str) -> PuzzleResult {
+    Err(PuzzleError::NotImplemented)
+}
+
+// Drop `unsolved` for each part once it is solved
+pub fn puzzle() -> AocPuzzle {
+    AocPuzzle::new({year}, {day}, silver, gold)
+        .unsolved(PuzzlePart::Silver)
+        .unsolved(PuzzlePart::Gold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::aoc_tests;
+
+    // Name the parts with an answer in input/examples/answers.toml and fill in the actual
+    // answers as they are found, `_` leaves a part out
+    aoc_tests! {
+        {year}, {day},
+        example 1 => (_, _),
+        actual => (_, _),
+    }
+}
+"#;
+
+/// Writes the skeleton module for a new day, and empty input and example files unless they
+/// already exist.
+/// The build script picks up the new module, so it doesn't need to be registered anywhere.
+/// Returns the paths of the files that were created.
+pub fn create_day(
+    year: u16,
+    day: u8,
+    input_set: &InputSet,
+    example_dir: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    if !Path::new("./src").is_dir() {
+        return Err("Source directory ./src not found, run from the repository root".to_string());
+    }
+
+    let module_dir = PathBuf::from(format!("./src/y{}/day_{:02}", year, day));
+    let module_path = module_dir.join("mod.rs");
+    if module_path.exists() {
+        return Err(format!(
+            "{} day {} already exists: {}",
+            year,
+            day,
+            module_path.display()
+        ));
+    }
+
+    let mut created = Vec::new();
+
+    fs::create_dir_all(&module_dir)
+        .and_then(|_| fs::write(&module_path, render_template(year, day)))
+        .map_err(|err| format!("Failed to write {}: {}", module_path.display(), err))?;
+    created.push(module_path);
+
+    let example_path = example_dir.join(format!("{}_{:02}_1.txt", year, day));
+    for (dir, path) in [
+        (input_set.dir.as_path(), input_set.path(year, day)),
+        (example_dir, example_path),
+    ] {
+        if !path.exists() {
+            fs::create_dir_all(dir)
+                .and_then(|_| fs::write(&path, ""))
+                .map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+            created.push(path);
+        }
+    }
+
+    Ok(created)
+}
+
+fn render_template(year: u16, day: u8) -> String {
+    TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template_is_filled_in() {
+        let module = render_template(2024, 2);
+        assert!(module.contains("AocPuzzle::new(2024, 2, silver, gold)"));
+        assert!(module.contains("2024, 2,\n"));
+        assert!(module.contains("example 1 => (_, _),"));
+        assert!(!module.contains("{year}") && !module.contains("{day}"));
+    }
+}
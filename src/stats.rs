@@ -0,0 +1,98 @@
+use serde::Serialize;
+use std::ops::AddAssign;
+
+/// Summary statistics over repeated timings of the same part, in milliseconds.
+#[derive(Clone, Copy, Default, Serialize)]
+pub struct TimingStats {
+    pub runs: usize,
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub mean_ms: f64,
+    pub p95_ms: f64,
+    pub stddev_ms: f64,
+}
+
+impl TimingStats {
+    pub fn from_samples(samples: &[f64]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let n = sorted.len();
+        let mean = sorted.iter().sum::<f64>() / n as f64;
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        } else {
+            sorted[n / 2]
+        };
+        // Nearest-rank percentile, so p95 is always one of the observed timings
+        let p95 = sorted[(n as f64 * 0.95).ceil() as usize - 1];
+        let variance = if n > 1 {
+            sorted.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            runs: n,
+            min_ms: sorted[0],
+            median_ms: median,
+            mean_ms: mean,
+            p95_ms: p95,
+            stddev_ms: variance.sqrt(),
+        }
+    }
+}
+
+/// Sums of the per-part statistics across a whole run.
+#[derive(Clone, Copy, Default, Serialize)]
+pub struct TimingTotals {
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub mean_ms: f64,
+    pub p95_ms: f64,
+}
+
+impl AddAssign<&TimingStats> for TimingTotals {
+    fn add_assign(&mut self, stats: &TimingStats) {
+        self.min_ms += stats.min_ms;
+        self.median_ms += stats.median_ms;
+        self.mean_ms += stats.mean_ms;
+        self.p95_ms += stats.p95_ms;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_odd_number_of_samples() {
+        let stats = TimingStats::from_samples(&[5.0, 1.0, 3.0, 2.0, 4.0]);
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min_ms, 1.0);
+        assert_eq!(stats.median_ms, 3.0);
+        assert_eq!(stats.mean_ms, 3.0);
+        assert_eq!(stats.p95_ms, 5.0);
+        assert!((stats.stddev_ms - 2.5f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stats_even_number_of_samples() {
+        let samples: Vec<f64> = (1..=20).map(f64::from).collect();
+        let stats = TimingStats::from_samples(&samples);
+        assert_eq!(stats.median_ms, 10.5);
+        assert_eq!(stats.p95_ms, 19.0);
+    }
+
+    #[test]
+    fn stats_single_sample() {
+        let stats = TimingStats::from_samples(&[2.0]);
+        assert_eq!(stats.median_ms, 2.0);
+        assert_eq!(stats.p95_ms, 2.0);
+        assert_eq!(stats.stddev_ms, 0.0);
+    }
+}
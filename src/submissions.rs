@@ -0,0 +1,233 @@
+use crate::aoc_client::SubmitOutcome;
+use crate::aoc_puzzle::PuzzlePart;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// An answer submitted to the server, and what came back.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: PuzzlePart,
+    pub answer: String,
+    pub outcome: SubmitOutcome,
+    /// When the answer was submitted, in seconds since the Unix epoch
+    pub submitted_at: u64,
+    /// How long the server asked to wait before the next answer, in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_s: Option<u64>,
+}
+
+/// Every answer submitted for an input set, kept in `submissions.json` next to its inputs.
+/// The server only says an answer is wrong, too high or too low once, so the ledger remembers
+/// it and keeps the same answer, or one outside the known bounds, from being submitted again.
+pub struct Ledger {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Loads the ledger at `path`, or starts an empty one if nothing has been submitted yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let submissions = if path.exists() {
+            let content = fs::read_to_string(path).map_err(|err| {
+                format!(
+                    "Failed to read submissions file {}: {}",
+                    path.display(),
+                    err
+                )
+            })?;
+            serde_json::from_str(&content)
+                .map_err(|err| format!("Invalid submissions file {}: {}", path.display(), err))?
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            submissions,
+        })
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("Failed to create {}: {}", dir.display(), err))?;
+        }
+        fs::write(
+            &self.path,
+            serde_json::to_string_pretty(&self.submissions)
+                .expect("Failed to serialize submissions"),
+        )
+        .map_err(|err| {
+            format!(
+                "Failed to write submissions file {}: {}",
+                self.path.display(),
+                err
+            )
+        })
+    }
+
+    fn attempts(&self, year: u16, day: u8, part: PuzzlePart) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part)
+    }
+
+    /// The answer the server accepted for a part, once it is solved.
+    pub fn right_answer(&self, year: u16, day: u8, part: PuzzlePart) -> Option<&str> {
+        self.attempts(year, day, part)
+            .find(|s| s.outcome == SubmitOutcome::Right)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Checks an answer against the earlier attempts, returning why it shouldn't be submitted:
+    /// it was rejected before, it is outside the bounds of the answers that were too high or
+    /// too low, or the server asked to wait longer before the next answer. `now` is in seconds
+    /// since the Unix epoch.
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: PuzzlePart,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), String> {
+        if let Some(earlier) = self
+            .attempts(year, day, part)
+            .find(|s| s.answer == answer && s.outcome.is_wrong())
+        {
+            return Err(format!(
+                "{} was already submitted, and was {}",
+                answer,
+                earlier.outcome.as_str()
+            ));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome| {
+                self.attempts(year, day, part)
+                    .filter(move |s| s.outcome == outcome)
+                    .filter_map(|s| s.answer.parse::<i128>().ok())
+            };
+            if let Some(high) = bound(SubmitOutcome::TooHigh).min() {
+                if value >= high {
+                    return Err(format!(
+                        "{} is not below {}, which is too high",
+                        answer, high
+                    ));
+                }
+            }
+            if let Some(low) = bound(SubmitOutcome::TooLow).max() {
+                if value <= low {
+                    return Err(format!("{} is not above {}, which is too low", answer, low));
+                }
+            }
+        }
+
+        // The server limits the answers of an account as a whole, not per puzzle
+        let wait_until = self
+            .submissions
+            .iter()
+            .filter_map(|s| Some(s.submitted_at + s.wait_s?))
+            .max();
+        match wait_until {
+            Some(until) if until > now => Err(format!(
+                "the server asked to wait {}s longer before the next answer",
+                until - now
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn submission(answer: &str, outcome: SubmitOutcome) -> Submission {
+        Submission {
+            year: 2025,
+            day: 1,
+            part: PuzzlePart::Silver,
+            answer: answer.to_string(),
+            outcome,
+            submitted_at: 1000,
+            wait_s: None,
+        }
+    }
+
+    fn ledger(submissions: Vec<Submission>) -> Ledger {
+        Ledger {
+            path: PathBuf::from("submissions.json"),
+            submissions,
+        }
+    }
+
+    #[test]
+    fn wrong_answers_are_not_submitted_again() {
+        let ledger = ledger(vec![
+            submission("abc", SubmitOutcome::Wrong),
+            submission("500", SubmitOutcome::TooHigh),
+            submission("800", SubmitOutcome::TooHigh),
+            submission("100", SubmitOutcome::TooLow),
+            submission("250", SubmitOutcome::RateLimited),
+        ]);
+        let check = |answer| ledger.check(2025, 1, PuzzlePart::Silver, answer, 2000);
+
+        assert!(check("abc").unwrap_err().contains("already submitted"));
+        assert!(check("500").unwrap_err().contains("already submitted"));
+        assert_eq!(
+            check("600"),
+            Err("600 is not below 500, which is too high".to_string())
+        );
+        assert_eq!(
+            check("-5"),
+            Err("-5 is not above 100, which is too low".to_string())
+        );
+        assert_eq!(check("250"), Ok(()));
+        assert_eq!(check("def"), Ok(()));
+
+        // The bounds of one part don't apply to the other
+        assert_eq!(ledger.check(2025, 1, PuzzlePart::Gold, "600", 2000), Ok(()));
+        assert_eq!(ledger.right_answer(2025, 1, PuzzlePart::Silver), None);
+    }
+
+    #[test]
+    fn waits_are_enforced_for_every_puzzle() {
+        let mut wrong = submission("42", SubmitOutcome::Wrong);
+        wrong.wait_s = Some(60);
+        let mut right = submission("7", SubmitOutcome::Right);
+        right.part = PuzzlePart::Gold;
+        let ledger = ledger(vec![wrong, right]);
+
+        assert_eq!(
+            ledger.check(2025, 2, PuzzlePart::Silver, "1", 1045),
+            Err("the server asked to wait 15s longer before the next answer".to_string())
+        );
+        assert_eq!(ledger.check(2025, 2, PuzzlePart::Silver, "1", 1060), Ok(()));
+        assert_eq!(ledger.right_answer(2025, 1, PuzzlePart::Gold), Some("7"));
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = env::temp_dir()
+            .join(format!("aoc_submissions_test_{}", std::process::id()))
+            .join("submissions.json");
+        let mut ledger = Ledger::load(&path).unwrap();
+        ledger.record(submission("500", SubmitOutcome::TooHigh));
+        ledger.save().unwrap();
+
+        let loaded = Ledger::load(&path).unwrap();
+        assert_eq!(
+            loaded.submissions,
+            vec![submission("500", SubmitOutcome::TooHigh)]
+        );
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,280 @@
+//! Helpers for the tests of the puzzles: reading their inputs and examples, and the
+//! `aoc_tests!` macro that generates the usual example and actual input tests.
+
+use crate::answers::ExpectedAnswers;
+use crate::aoc_client::has_content;
+use crate::aoc_puzzle::{AocPuzzle, PuzzlePart};
+use crate::examples::EXAMPLES_DIR;
+use crate::inputs::{read_input_file, InputSet, ANSWERS_FILE, DEFAULT_INPUT_DIR, INPUT_DIR_ENV};
+use std::env;
+use std::path::PathBuf;
+
+/// The input directory of the tests: `AOC_INPUT_DIR`, or `./input` when it isn't set.
+fn input_dir() -> PathBuf {
+    PathBuf::from(env::var(INPUT_DIR_ENV).unwrap_or_else(|_| DEFAULT_INPUT_DIR.to_string()))
+}
+
+fn test_input_path(year: u16, day: u8) -> PathBuf {
+    InputSet::new(&input_dir(), None).unwrap().path(year, day)
+}
+
+pub fn read_example(year: u16, day: u8, number: u32) -> String {
+    let path = input_dir()
+        .join(EXAMPLES_DIR)
+        .join(format!("{}_{:02}_{}.txt", year, day, number));
+    read_input_file(&path).unwrap()
+}
+
+/// The actual input of a puzzle, or `None` with a message when it isn't there or is empty, so
+/// that a checkout without inputs can still run the other tests.
+pub fn actual_input(year: u16, day: u8) -> Option<String> {
+    let path = test_input_path(year, day);
+    if !has_content(&path) {
+        eprintln!(
+            "skipping {} day {}: input file {} not found",
+            year,
+            day,
+            path.display()
+        );
+        return None;
+    }
+    Some(read_input_file(&path).unwrap())
+}
+
+/// Runs a part and checks its answers. For `both`, `expected` holds the silver and gold answers.
+pub fn check_part(
+    puzzle: &AocPuzzle,
+    part: PuzzlePart,
+    input: &str,
+    expected: &[&str],
+    what: &str,
+) {
+    let answers = puzzle.run_part(part, input).unwrap_or_else(|err| {
+        panic!(
+            "{} day {} {} ({}): {}",
+            puzzle.year,
+            puzzle.day,
+            part.as_str(),
+            what,
+            err
+        )
+    });
+    let texts: Vec<String> = answers.iter().map(|answer| answer.to_string()).collect();
+    assert!(
+        answers.len() == expected.len()
+            && answers
+                .iter()
+                .zip(expected)
+                .all(|(answer, expected)| answer.matches(expected)),
+        "{} day {} {} ({}): got {:?}, expected {:?}",
+        puzzle.year,
+        puzzle.day,
+        part.as_str(),
+        what,
+        texts,
+        expected
+    );
+}
+
+/// Runs a part on an example and checks its answers against the ones in the `answers.toml`
+/// of the examples, which must have them.
+pub fn check_example(puzzle: &AocPuzzle, part: PuzzlePart, number: u32) {
+    let (year, day) = (puzzle.year, puzzle.day);
+    let path = input_dir().join(EXAMPLES_DIR).join(ANSWERS_FILE);
+    let answers = ExpectedAnswers::load_examples(&path).unwrap();
+    let what = format!("example {}", number);
+    let expected = answers
+        .get(&number)
+        .and_then(|answers| answers.expected(year, day, part))
+        .unwrap_or_else(|| {
+            panic!(
+                "{} day {} {} ({}): no answer in {}",
+                year,
+                day,
+                part.as_str(),
+                what,
+                path.display()
+            )
+        });
+    let expected: Vec<&str> = expected.iter().map(String::as_str).collect();
+    check_part(
+        puzzle,
+        part,
+        &read_example(year, day, number),
+        &expected,
+        &what,
+    );
+}
+
+/// Checks that a dedicated `both` solution agrees with the silver and gold solutions on an
+/// input. Puzzles without one have nothing to check, and neither do inputs a part fails on.
+pub fn check_both_matches_parts(puzzle: &AocPuzzle, input: &str, what: &str) {
+    if !puzzle.has_both() {
+        return;
+    }
+    let (Ok(silver), Ok(gold)) = (
+        puzzle.run_part(PuzzlePart::Silver, input),
+        puzzle.run_part(PuzzlePart::Gold, input),
+    ) else {
+        return;
+    };
+    let expected: Vec<String> = silver
+        .iter()
+        .chain(&gold)
+        .map(|answer| answer.to_string())
+        .collect();
+    let expected: Vec<&str> = expected.iter().map(String::as_str).collect();
+    check_part(puzzle, PuzzlePart::Both, input, &expected, what);
+}
+
+/// Generates the tests of a puzzle, for silver, gold and both:
+///
+/// ```ignore
+/// aoc_tests! {
+///     2025, 11,
+///     example 1 => (silver, _),
+///     example 2 => (_, gold),
+///     actual => ("758", "490695961032000"),
+/// }
+/// ```
+///
+/// Each example is an `input/examples` file, checked against its answers in the `answers.toml`
+/// there, and `_` leaves out a part it doesn't apply to.
+/// `both` is only checked with both answers known, and so is whether a dedicated `both` agrees
+/// with silver and gold. The actual input tests pass with a message when the input file isn't
+/// there. Invoke it in the `tests` module, after `use super::*;`.
+macro_rules! aoc_tests {
+    ($year:literal, $day:literal, $(example $n:literal => ($silver:tt, $gold:tt)),+ $(,)?) => {
+        $crate::test_support::aoc_tests!(@examples $year, $day, $($n => ($silver, $gold)),+);
+    };
+    ($year:literal, $day:literal, actual => ($silver:tt, $gold:tt) $(,)?) => {
+        $crate::test_support::aoc_tests!(@actual $year, $day, ($silver, $gold));
+    };
+    (
+        $year:literal, $day:literal,
+        $(example $n:literal => ($silver:tt, $gold:tt),)+
+        actual => ($actual_silver:tt, $actual_gold:tt) $(,)?
+    ) => {
+        $crate::test_support::aoc_tests!(@examples $year, $day, $($n => ($silver, $gold)),+);
+        $crate::test_support::aoc_tests!(@actual $year, $day, ($actual_silver, $actual_gold));
+    };
+
+    (@examples $year:literal, $day:literal, $($n:literal => ($silver:tt, $gold:tt)),+) => {
+        mod examples {
+            use super::*;
+            use $crate::aoc_puzzle::PuzzlePart;
+            use $crate::test_support::{check_both_matches_parts, check_example, read_example};
+
+            #[test]
+            fn silver() {
+                $(if $crate::test_support::aoc_tests!(@applies $silver) {
+                    check_example(&puzzle(), PuzzlePart::Silver, $n);
+                })+
+            }
+
+            #[test]
+            fn gold() {
+                $(if $crate::test_support::aoc_tests!(@applies $gold) {
+                    check_example(&puzzle(), PuzzlePart::Gold, $n);
+                })+
+            }
+
+            #[test]
+            fn both() {
+                $(if $crate::test_support::aoc_tests!(@applies $silver $gold) {
+                    check_example(&puzzle(), PuzzlePart::Both, $n);
+                })+
+            }
+
+            #[test]
+            fn both_matches_parts() {
+                // Examples that only apply to one part may not work with the other
+                $(if $crate::test_support::aoc_tests!(@applies $silver $gold) {
+                    check_both_matches_parts(
+                        &puzzle(),
+                        &read_example($year, $day, $n),
+                        concat!("example ", $n),
+                    );
+                })+
+            }
+        }
+    };
+
+    (@actual $year:literal, $day:literal, ($silver:tt, $gold:tt)) => {
+        mod actual {
+            use super::*;
+            use $crate::aoc_puzzle::PuzzlePart;
+            use $crate::test_support::{actual_input, check_both_matches_parts, check_part};
+
+            #[test]
+            fn silver() {
+                if let Some(input) = actual_input($year, $day) {
+                    $crate::test_support::aoc_tests!(
+                        @check input, PuzzlePart::Silver, ($silver), "actual input"
+                    );
+                }
+            }
+
+            #[test]
+            fn gold() {
+                if let Some(input) = actual_input($year, $day) {
+                    $crate::test_support::aoc_tests!(
+                        @check input, PuzzlePart::Gold, ($gold), "actual input"
+                    );
+                }
+            }
+
+            #[test]
+            fn both() {
+                if let Some(input) = actual_input($year, $day) {
+                    $crate::test_support::aoc_tests!(
+                        @check input, PuzzlePart::Both, ($silver, $gold), "actual input"
+                    );
+                }
+            }
+
+            #[test]
+            fn both_matches_parts() {
+                if let Some(input) = actual_input($year, $day) {
+                    $crate::test_support::aoc_tests!(
+                        @consistent input, ($silver, $gold), "actual input"
+                    );
+                }
+            }
+        }
+    };
+
+    // A part is only checked when all of its answers are given
+    (@check $input:expr, $part:expr, ($($answer:tt),+), $what:expr) => {
+        if let Some(expected) = $crate::test_support::aoc_tests!(@expected [] $($answer)+) {
+            check_part(&puzzle(), $part, &$input, &expected, $what);
+        }
+    };
+    // Inputs that only apply to one part may not work with the other, so `_` leaves them out
+    (@consistent $input:expr, ($($answer:tt),+), $what:expr) => {
+        if $crate::test_support::aoc_tests!(@expected [] $($answer)+).is_some() {
+            check_both_matches_parts(&puzzle(), &$input, $what);
+        }
+    };
+    (@expected [$($known:literal)*] _ $($rest:tt)*) => {
+        None::<[&str; 0]>
+    };
+    (@expected [$($known:literal)*] $answer:literal $($rest:tt)*) => {
+        $crate::test_support::aoc_tests!(@expected [$($known)* $answer] $($rest)*)
+    };
+    (@expected [$($known:literal)*]) => {
+        Some([$($known),*])
+    };
+    // Whether an example applies to all the given parts, which it doesn't when one is `_`
+    (@applies _ $($rest:tt)*) => {
+        false
+    };
+    (@applies $part:ident $($rest:tt)*) => {
+        $crate::test_support::aoc_tests!(@applies $($rest)*)
+    };
+    (@applies) => {
+        true
+    };
+}
+
+pub(crate) use aoc_tests;
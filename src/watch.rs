@@ -0,0 +1,220 @@
+//! Watch mode: polling the input and example files of the selected puzzles, and keeping the
+//! last answers and timings of each part so that a rerun can show what changed.
+
+use crate::answer::Answer;
+use crate::aoc_puzzle::{PartResult, PuzzlePart};
+use crate::reporter::timeout_text;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// What a file looked like when it was polled: its modification time and size, or nothing
+/// while it doesn't exist. The size catches changes made within the resolution of the time.
+type FileState = Option<(SystemTime, u64)>;
+
+fn file_state(path: &Path) -> FileState {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Polls a set of files for changes, without any notification service.
+#[derive(Default)]
+pub struct FileWatcher {
+    states: HashMap<PathBuf, FileState>,
+}
+
+impl FileWatcher {
+    /// Records the current state of the files, returning the ones that changed since they
+    /// were last polled, or that weren't polled before, in the order they were given.
+    pub fn poll<'a>(&mut self, paths: impl IntoIterator<Item = &'a Path>) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for path in paths {
+            let state = file_state(path);
+            if self.states.get(path) != Some(&state) {
+                self.states.insert(path.to_path_buf(), state);
+                changed.push(path.to_path_buf());
+            }
+        }
+        changed
+    }
+}
+
+/// How a part came out, as shown in the answer column.
+#[derive(Clone, Debug, PartialEq)]
+struct Outcome {
+    answer: String,
+    /// Only parts that were actually solved have a time worth comparing
+    duration_ms: Option<f64>,
+}
+
+impl Outcome {
+    fn of(result: &PartResult) -> Self {
+        let answer = if let Some(reason) = result.skipped {
+            reason.to_string()
+        } else if let Some(error) = &result.error {
+            format!("ERROR: {}", error)
+        } else if let Some(error) = &result.puzzle_error {
+            error.to_string()
+        } else if let Some(timeout_s) = result.timeout_s {
+            timeout_text(timeout_s)
+        } else {
+            let answers: Vec<String> = result.answers.iter().map(Answer::to_string).collect();
+            return Self {
+                answer: answers.join(", "),
+                duration_ms: Some(result.duration_ms),
+            };
+        };
+        Self {
+            answer,
+            duration_ms: None,
+        }
+    }
+}
+
+/// What a rerun of a part changed since its last run on the same input.
+#[derive(Debug, Default, PartialEq)]
+pub struct Change {
+    /// The answer is set when it differs from the one before, which is kept here
+    pub answer_was: Option<String>,
+    pub delta_ms: Option<f64>,
+}
+
+/// The last answers and timings of each part on each input.
+#[derive(Default)]
+pub struct History {
+    last: HashMap<(u16, u8, PuzzlePart, Option<String>), Outcome>,
+}
+
+impl History {
+    /// Records a result, returning how it differs from the last run of the same part on the
+    /// same input. The first run of a part has nothing to compare with.
+    pub fn record(&mut self, result: &PartResult) -> Change {
+        let key = (result.year, result.day, result.part, result.input.clone());
+        let outcome = Outcome::of(result);
+        let Some(previous) = self.last.insert(key, outcome.clone()) else {
+            return Change::default();
+        };
+        Change {
+            answer_was: (previous.answer != outcome.answer).then_some(previous.answer),
+            delta_ms: outcome
+                .duration_ms
+                .zip(previous.duration_ms)
+                .map(|(now, before)| now - before),
+        }
+    }
+}
+
+/// A row of the watch output: the time of the part and how much it changed since the last run,
+/// then the answer, with the one before when it changed and the expected one when it's wrong.
+pub fn row(result: &PartResult, change: &Change) -> String {
+    let outcome = Outcome::of(result);
+    let duration_str = match outcome.duration_ms {
+        Some(duration_ms) => format!("{:.1} ms", duration_ms),
+        None => "--".to_string(),
+    };
+    let delta_str = change
+        .delta_ms
+        .map_or(String::new(), |delta_ms| format!("{:+.1} ms", delta_ms));
+
+    let mut answer = outcome.answer;
+    if let Some(was) = &change.answer_was {
+        answer += &format!(" (was {})", was);
+    }
+    if let Some(expected) = &result.expected {
+        answer += &format!(" (expected {})", expected.join(", "));
+    }
+    format!(
+        "{} | {:>2} | {:<7} | {:<10} | {:>10} | {:>10} | {}",
+        result.year,
+        result.day,
+        result.part.as_str(),
+        result.input.as_deref().unwrap_or("default"),
+        duration_str,
+        delta_str,
+        answer
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle_error::PuzzleError;
+    use std::env;
+
+    #[test]
+    fn poll_changed_files() {
+        let dir = env::temp_dir().join(format!("aoc_watch_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("2025_09.txt");
+        let example = dir.join("2025_09_1.txt");
+        fs::write(&example, "7,1\n").unwrap();
+        let paths = [input.as_path(), example.as_path()];
+
+        let mut watcher = FileWatcher::default();
+        assert_eq!(watcher.poll(paths), vec![input.clone(), example.clone()]);
+        assert!(watcher.poll(paths).is_empty());
+
+        // Creating a missing file is a change, and so is writing to one
+        fs::write(&input, "1,2\n").unwrap();
+        fs::write(&example, "7,1\n11,1\n").unwrap();
+        assert_eq!(watcher.poll(paths), vec![input, example]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn history_shows_what_changed() {
+        let mut history = History::default();
+        let mut result = PartResult::new(2025, 9, PuzzlePart::Silver);
+        result.answers = vec![Answer::Int(50)];
+        result.duration_ms = 2.0;
+        assert_eq!(history.record(&result), Change::default());
+
+        result.duration_ms = 1.5;
+        assert_eq!(
+            history.record(&result),
+            Change {
+                answer_was: None,
+                delta_ms: Some(-0.5)
+            }
+        );
+
+        result.answers.clear();
+        result.puzzle_error = Some(PuzzleError::NoSolution);
+        assert_eq!(
+            history.record(&result),
+            Change {
+                answer_was: Some("50".to_string()),
+                delta_ms: None
+            }
+        );
+
+        // Each input has its own history
+        result.input = Some("example 1".to_string());
+        assert_eq!(history.record(&result), Change::default());
+    }
+
+    #[test]
+    fn format_rows() {
+        let mut result = PartResult::new(2025, 9, PuzzlePart::Both);
+        result.answers = vec![Answer::Int(50), Answer::Int(24)];
+        result.duration_ms = 1.25;
+        result.expected = Some(vec!["50".to_string(), "25".to_string()]);
+        let change = Change {
+            answer_was: Some("50, 25".to_string()),
+            delta_ms: Some(0.5),
+        };
+        assert_eq!(
+            row(&result, &change),
+            "2025 |  9 | both    | default    |     1.2 ms |    +0.5 ms | 50, 24 (was 50, 25) (expected 50, 25)"
+        );
+
+        let mut skipped = PartResult::new(2025, 9, PuzzlePart::Gold);
+        skipped.skipped = Some("No answer for this example");
+        skipped.input = Some("example 1".to_string());
+        assert_eq!(
+            row(&skipped, &Change::default()),
+            "2025 |  9 | gold    | example 1  |         -- |            | No answer for this example"
+        );
+    }
+}
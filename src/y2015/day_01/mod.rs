@@ -1,16 +1,17 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
+use crate::puzzle_error::PuzzleError;
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let result: i32 = input.chars().fold(0, |floor, c| match c {
         '(' => floor + 1,
         ')' => floor - 1,
         _ => floor,
     });
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut floor = 0;
     for (i, c) in input.chars().enumerate() {
         match c {
@@ -19,10 +20,10 @@ pub fn gold(input: &str) -> Box<dyn Display> {
             _ => {}
         }
         if floor < 0 {
-            return Box::new(i + 1);
+            return Ok(Answer::from(i + 1));
         }
     }
-    Box::new(0)
+    Err(PuzzleError::NoSolution)
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -32,35 +33,13 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
-
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(")())())").to_string(), "-3");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2015, 1);
-        assert_eq!(silver(&input).to_string(), "232");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(")").to_string(), "1");
-        assert_eq!(gold("()())").to_string(), "5");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2015, 1);
-        assert_eq!(gold(&input).to_string(), "1783");
+    use crate::test_support::aoc_tests;
+
+    aoc_tests! {
+        2015, 1,
+        example 1 => (silver, _),
+        example 2 => (_, gold),
+        example 3 => (_, gold),
+        actual => ("232", "1783"),
     }
 }
@@ -1,5 +1,6 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
+use crate::puzzle_error::PuzzleError;
 
 #[derive(Clone, Copy)]
 enum Direction {
@@ -42,7 +43,7 @@ impl Direction {
     }
 }
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut x = 0;
     let mut y = 0;
     let mut dir = Direction::Up;
@@ -62,7 +63,7 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         y += dy;
     }
 
-    Box::new(x.abs() + y.abs())
+    Ok(Answer::from(x.abs() + y.abs()))
 }
 
 #[derive(Clone, Copy)]
@@ -91,7 +92,7 @@ impl Path {
     }
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut x = 0;
     let mut y = 0;
     let mut horizontal_paths: Vec<Path> = Vec::new();
@@ -121,14 +122,14 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         if dir.is_horizontal() {
             for v_path in &vertical_paths {
                 if let Some((ix, iy)) = Path::intersects_h_v(&path, v_path) {
-                    return Box::new(ix.abs() + iy.abs());
+                    return Ok(Answer::from(ix.abs() + iy.abs()));
                 }
             }
             horizontal_paths.push(path);
         } else {
             for h_path in &horizontal_paths {
                 if let Some((ix, iy)) = Path::intersects_h_v(h_path, &path) {
-                    return Box::new(ix.abs() + iy.abs());
+                    return Ok(Answer::from(ix.abs() + iy.abs()));
                 }
             }
             vertical_paths.push(path);
@@ -138,7 +139,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         y += to_dy;
     }
 
-    Box::new(0)
+    Err(PuzzleError::NoSolution)
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -148,34 +149,12 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver("R5, L5, R5, R3").to_string(), "12");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2016, 1);
-        assert_eq!(silver(&input).to_string(), "209");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold("R8, R4, R4, R8").to_string(), "4");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2016, 1);
-        assert_eq!(gold(&input).to_string(), "136");
+    aoc_tests! {
+        2016, 1,
+        example 1 => (silver, _),
+        example 2 => (_, gold),
+        actual => ("209", "136"),
     }
 }
@@ -1,12 +1,12 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut sum = 0;
     let chars: Vec<char> = input.chars().collect();
 
     if chars.is_empty() {
-        return Box::new(sum);
+        return Ok(Answer::from(sum));
     }
 
     let first_char = chars[0];
@@ -25,10 +25,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         sum += value.to_digit(10).unwrap() as i32;
     }
 
-    Box::new(sum)
+    Ok(Answer::from(sum))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut sum = 0;
     let chars: Vec<char> = input.chars().collect();
     let len = chars.len();
@@ -41,7 +41,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(sum)
+    Ok(Answer::from(sum))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -51,41 +51,19 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
-
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver("1122").to_string(), "3");
-        assert_eq!(silver("1111").to_string(), "4");
-        assert_eq!(silver("1234").to_string(), "0");
-        assert_eq!(silver("91212129").to_string(), "9");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2017, 1);
-        assert_eq!(silver(&input).to_string(), "1097");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold("1212").to_string(), "6");
-        assert_eq!(gold("1221").to_string(), "0");
-        assert_eq!(gold("123425").to_string(), "4");
-        assert_eq!(gold("123123").to_string(), "12");
-        assert_eq!(gold("12131415").to_string(), "4");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2017, 1);
-        assert_eq!(gold(&input).to_string(), "1188");
+    use crate::test_support::aoc_tests;
+
+    aoc_tests! {
+        2017, 1,
+        example 1 => (silver, _),
+        example 2 => (silver, _),
+        example 3 => (silver, _),
+        example 4 => (silver, _),
+        example 5 => (_, gold),
+        example 6 => (_, gold),
+        example 7 => (_, gold),
+        example 8 => (_, gold),
+        example 9 => (_, gold),
+        actual => ("1097", "1188"),
     }
 }
@@ -1,5 +1,5 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
 fn required_fuel(mass: i32) -> i32 {
     (mass / 3) - 2
@@ -15,21 +15,21 @@ fn additional_required_fuel(fuel: i32) -> i32 {
     total
 }
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let result: i32 = input
         .lines()
         .map(|x| required_fuel(x.parse::<i32>().unwrap()))
         .sum();
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let result: i32 = input
         .lines()
         .map(|x| required_fuel(x.parse::<i32>().unwrap()))
         .map(|x| x + additional_required_fuel(x))
         .sum();
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -39,36 +39,12 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let test_input = "12\n14\n1969\n100756";
-        assert_eq!(silver(test_input).to_string(), "34241");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2019, 1);
-        assert_eq!(silver(&input).to_string(), "3147032");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let test_input = "14\n1969\n100756";
-        assert_eq!(gold(test_input).to_string(), "51314");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2019, 1);
-        assert_eq!(gold(&input).to_string(), "4717699");
+    aoc_tests! {
+        2019, 1,
+        example 1 => (silver, _),
+        example 2 => (_, gold),
+        actual => ("3147032", "4717699"),
     }
 }
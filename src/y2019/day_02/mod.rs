@@ -1,26 +1,45 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
+use crate::puzzle_error::PuzzleError;
 
-pub fn silver(input: &str) -> Box<dyn Display> {
-    let mut program: Vec<usize> = input.split(',').map(|x| x.parse().unwrap()).collect();
+/// The initial memory of the program, which every run starts again from.
+pub struct Program(Vec<usize>);
+
+pub fn parse(input: &str) -> PuzzleResult<Program> {
+    parse_program(input).map(Program)
+}
+
+pub fn silver(initial: &Program) -> PuzzleResult {
+    let mut program = initial.0.clone();
     program[1] = 12;
     program[2] = 2;
     let result = run_intcode(&mut program);
-    Box::new(result[0])
+    Ok(Answer::from(result[0]))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(initial: &Program) -> PuzzleResult {
     for i in 0..100 {
         for j in 0..100 {
-            let mut program: Vec<usize> = input.split(',').map(|x| x.parse().unwrap()).collect();
+            let mut program = initial.0.clone();
             program[1] = i;
             program[2] = j;
             if run_intcode(&mut program)[0] == 19690720 {
-                return Box::new(100 * i + j);
+                return Ok(Answer::from(100 * i + j));
             }
         }
     }
-    Box::new(0)
+    Err(PuzzleError::NoSolution)
+}
+
+fn parse_program(input: &str) -> Result<Vec<usize>, PuzzleError> {
+    input
+        .split(',')
+        .map(|value| {
+            value.trim().parse().map_err(|_| {
+                PuzzleError::parse_at(input, value, format!("invalid opcode '{}'", value))
+            })
+        })
+        .collect()
 }
 
 fn run_intcode(p: &mut [usize]) -> &[usize] {
@@ -43,19 +62,17 @@ fn run_intcode(p: &mut [usize]) -> &[usize] {
 }
 
 pub fn puzzle() -> AocPuzzle {
-    AocPuzzle::new(2019, 2, silver, gold)
+    AocPuzzle::parsed(2019, 2, parse, silver, gold)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
+    aoc_tests! {
+        2019, 2,
+        actual => ("4138658", "7264"),
     }
 
     #[test]
@@ -66,14 +83,10 @@ mod tests {
     }
 
     #[test]
-    fn silver_actual_input() {
-        let input = read_input(2019, 2);
-        assert_eq!(silver(&input).to_string(), "4138658");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2019, 2);
-        assert_eq!(gold(&input).to_string(), "7264");
+    fn invalid_program() {
+        assert_eq!(
+            parse_program("1,0,0,3,\n99,x,0").unwrap_err(),
+            PuzzleError::parse(2, 4, "invalid opcode 'x'")
+        );
     }
 }
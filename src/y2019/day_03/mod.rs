@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut lines = input.lines();
     let wire1_instructions = lines.next().unwrap();
     let wire2_instructions = lines.next().unwrap();
@@ -22,10 +22,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(closest_intersection_dist)
+    Ok(Answer::from(closest_intersection_dist))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut lines = input.lines();
     let wire1_instructions = lines.next().unwrap();
     let wire2_instructions = lines.next().unwrap();
@@ -46,7 +46,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(closest_intersection_wire_length)
+    Ok(Answer::from(closest_intersection_wire_length))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -131,50 +131,12 @@ fn find_intersection(horizontal: &PathSection, vertical: &PathSection) -> Option
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83";
-        assert_eq!(silver(input).to_string(), "159");
-    }
-
-    #[test]
-    fn silver_test_input_2() {
-        let input =
-            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7";
-        assert_eq!(silver(input).to_string(), "135");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2019, 3);
-        assert_eq!(silver(&input).to_string(), "721");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83";
-        assert_eq!(gold(input).to_string(), "610");
-    }
-
-    #[test]
-    fn gold_test_input_2() {
-        let input =
-            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7";
-        assert_eq!(gold(input).to_string(), "410");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2019, 3);
-        assert_eq!(gold(&input).to_string(), "7388");
+    aoc_tests! {
+        2019, 3,
+        example 1 => (silver, gold),
+        example 2 => (silver, gold),
+        actual => ("721", "7388"),
     }
 }
@@ -1,16 +1,16 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let (min, max) = parse_range(input);
     let count = (min..=max).filter(|&i| is_valid_password(i, false)).count();
-    Box::new(count)
+    Ok(Answer::from(count))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let (min, max) = parse_range(input);
     let count = (min..=max).filter(|&i| is_valid_password(i, true)).count();
-    Box::new(count)
+    Ok(Answer::from(count))
 }
 
 fn parse_range(input: &str) -> (u32, u32) {
@@ -59,36 +59,14 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver("111111-111111").to_string(), "1");
-        assert_eq!(silver("223450-223450").to_string(), "0");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2019, 4);
-        assert_eq!(silver(&input).to_string(), "1150");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold("123444-123444").to_string(), "0");
-        assert_eq!(gold("111122-111122").to_string(), "1");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2019, 4);
-        assert_eq!(gold(&input).to_string(), "748");
+    aoc_tests! {
+        2019, 4,
+        example 1 => (silver, _),
+        example 2 => (silver, _),
+        example 3 => (_, gold),
+        example 4 => (_, gold),
+        actual => ("1150", "748"),
     }
 }
@@ -1,5 +1,6 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
+use crate::puzzle_error::PuzzleError;
 
 #[derive(Debug, Clone, Copy)]
 enum ParameterMode {
@@ -7,16 +8,21 @@ enum ParameterMode {
     Immediate = 1,
 }
 
-impl From<i32> for ParameterMode {
-    fn from(value: i32) -> Self {
+impl TryFrom<i32> for ParameterMode {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, String> {
         match value {
-            0 => ParameterMode::Position,
-            1 => ParameterMode::Immediate,
-            _ => panic!("Invalid parameter mode: {}", value),
+            0 => Ok(ParameterMode::Position),
+            1 => Ok(ParameterMode::Immediate),
+            _ => Err(format!("invalid parameter mode {}", value)),
         }
     }
 }
 
+/// An instruction the program can't run, and its address.
+type InvalidInstruction = (usize, String);
+
 struct Instruction {
     opcode: i32,
     param1_mode: ParameterMode,
@@ -24,17 +30,17 @@ struct Instruction {
     param3_mode: ParameterMode,
 }
 
-fn get_instruction(instruction: i32) -> Instruction {
+fn get_instruction(instruction: i32) -> Result<Instruction, String> {
     let opcode = instruction % 100;
-    let param1_mode = ((instruction / 100) % 10).into();
-    let param2_mode = ((instruction / 1000) % 10).into();
-    let param3_mode = ((instruction / 10000) % 10).into();
-    Instruction {
+    let param1_mode = ((instruction / 100) % 10).try_into()?;
+    let param2_mode = ((instruction / 1000) % 10).try_into()?;
+    let param3_mode = ((instruction / 10000) % 10).try_into()?;
+    Ok(Instruction {
         opcode,
         param1_mode,
         param2_mode,
         param3_mode,
-    }
+    })
 }
 
 fn get_program_value(p: &[i32], index: usize, mode: ParameterMode) -> i32 {
@@ -44,22 +50,26 @@ fn get_program_value(p: &[i32], index: usize, mode: ParameterMode) -> i32 {
     }
 }
 
-fn set_program_value(p: &mut [i32], index: usize, mode: ParameterMode, value: i32) {
+fn set_program_value(
+    p: &mut [i32],
+    index: usize,
+    mode: ParameterMode,
+    value: i32,
+) -> Result<(), String> {
     match mode {
         ParameterMode::Position => {
             let addr = p[index] as usize;
             p[addr] = value;
+            Ok(())
         }
-        ParameterMode::Immediate => {
-            panic!("Immediate mode not supported for write operations");
-        }
+        ParameterMode::Immediate => Err("immediate mode is not supported for writes".to_string()),
     }
 }
 
-fn run_intcode(p: &mut [i32], input: i32) -> Vec<i32> {
+fn run_intcode(p: &mut [i32], input: i32) -> Result<Vec<i32>, InvalidInstruction> {
     let mut outputs = Vec::new();
     let mut i = 0;
-    let mut instruction = get_instruction(p[i]);
+    let mut instruction = get_instruction(p[i]).map_err(|err| (i, err))?;
 
     while instruction.opcode != 99 {
         match instruction.opcode {
@@ -67,19 +77,22 @@ fn run_intcode(p: &mut [i32], input: i32) -> Vec<i32> {
                 // Add
                 let val = get_program_value(p, i + 1, instruction.param1_mode)
                     + get_program_value(p, i + 2, instruction.param2_mode);
-                set_program_value(p, i + 3, instruction.param3_mode, val);
+                set_program_value(p, i + 3, instruction.param3_mode, val)
+                    .map_err(|err| (i, err))?;
                 i += 4;
             }
             2 => {
                 // Multiply
                 let val = get_program_value(p, i + 1, instruction.param1_mode)
                     * get_program_value(p, i + 2, instruction.param2_mode);
-                set_program_value(p, i + 3, instruction.param3_mode, val);
+                set_program_value(p, i + 3, instruction.param3_mode, val)
+                    .map_err(|err| (i, err))?;
                 i += 4;
             }
             3 => {
                 // Input
-                set_program_value(p, i + 1, instruction.param1_mode, input);
+                set_program_value(p, i + 1, instruction.param1_mode, input)
+                    .map_err(|err| (i, err))?;
                 i += 2;
             }
             4 => {
@@ -112,7 +125,8 @@ fn run_intcode(p: &mut [i32], input: i32) -> Vec<i32> {
                 } else {
                     0
                 };
-                set_program_value(p, i + 3, instruction.param3_mode, val);
+                set_program_value(p, i + 3, instruction.param3_mode, val)
+                    .map_err(|err| (i, err))?;
                 i += 4;
             }
             8 => {
@@ -124,26 +138,41 @@ fn run_intcode(p: &mut [i32], input: i32) -> Vec<i32> {
                 } else {
                     0
                 };
-                set_program_value(p, i + 3, instruction.param3_mode, val);
+                set_program_value(p, i + 3, instruction.param3_mode, val)
+                    .map_err(|err| (i, err))?;
                 i += 4;
             }
-            _ => panic!("Unknown opcode: {}", instruction.opcode),
+            opcode => return Err((i, format!("unknown opcode {}", opcode))),
         }
-        instruction = get_instruction(p[i]);
+        instruction = get_instruction(p[i]).map_err(|err| (i, err))?;
     }
-    outputs
+    Ok(outputs)
+}
+
+/// Runs the program in the input and returns its last output. An invalid value or instruction
+/// is reported at its position in the input.
+fn diagnostic_code(input: &str, system_id: i32) -> PuzzleResult {
+    let tokens: Vec<&str> = input.split(',').map(str::trim).collect();
+    let mut program = tokens
+        .iter()
+        .map(|token| {
+            token.parse().map_err(|_| {
+                PuzzleError::parse_at(input, token, format!("invalid value '{}'", token))
+            })
+        })
+        .collect::<PuzzleResult<Vec<i32>>>()?;
+    let outputs = run_intcode(&mut program, system_id)
+        .map_err(|(address, message)| PuzzleError::parse_at(input, tokens[address], message))?;
+    let code = outputs.last().ok_or(PuzzleError::NoSolution)?;
+    Ok(Answer::from(*code))
 }
 
-pub fn silver(input: &str) -> Box<dyn Display> {
-    let mut program: Vec<i32> = input.split(',').map(|x| x.parse().unwrap()).collect();
-    let outputs = run_intcode(&mut program, 1);
-    Box::new(outputs[outputs.len() - 1])
+pub fn silver(input: &str) -> PuzzleResult {
+    diagnostic_code(input, 1)
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
-    let mut program: Vec<i32> = input.split(',').map(|x| x.parse().unwrap()).collect();
-    let outputs = run_intcode(&mut program, 5);
-    Box::new(outputs[outputs.len() - 1])
+pub fn gold(input: &str) -> PuzzleResult {
+    diagnostic_code(input, 5)
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -153,20 +182,19 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
+    aoc_tests! {
+        2019, 5,
+        example 1 => (silver, _),
+        actual => ("4511442", "12648139"),
     }
 
     #[test]
     fn test_parameter_modes() {
         // Test immediate mode: 1002,4,3,4,33 multiplies 33 by 3 and stores at position 4
         let mut program = vec![1002, 4, 3, 4, 33];
-        run_intcode(&mut program, 0);
+        run_intcode(&mut program, 0).unwrap();
         assert_eq!(program[4], 99);
     }
 
@@ -174,11 +202,11 @@ mod tests {
     fn test_equals_position_mode() {
         // Using position mode, equal to 8
         let mut program = vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
-        let outputs = run_intcode(&mut program, 8);
+        let outputs = run_intcode(&mut program, 8).unwrap();
         assert_eq!(outputs[0], 1);
 
         let mut program = vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
-        let outputs = run_intcode(&mut program, 7);
+        let outputs = run_intcode(&mut program, 7).unwrap();
         assert_eq!(outputs[0], 0);
     }
 
@@ -186,11 +214,11 @@ mod tests {
     fn test_less_than_position_mode() {
         // Using position mode, less than 8
         let mut program = vec![3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8];
-        let outputs = run_intcode(&mut program, 7);
+        let outputs = run_intcode(&mut program, 7).unwrap();
         assert_eq!(outputs[0], 1);
 
         let mut program = vec![3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8];
-        let outputs = run_intcode(&mut program, 8);
+        let outputs = run_intcode(&mut program, 8).unwrap();
         assert_eq!(outputs[0], 0);
     }
 
@@ -198,11 +226,11 @@ mod tests {
     fn test_equals_immediate_mode() {
         // Using immediate mode, equal to 8
         let mut program = vec![3, 3, 1108, -1, 8, 3, 4, 3, 99];
-        let outputs = run_intcode(&mut program, 8);
+        let outputs = run_intcode(&mut program, 8).unwrap();
         assert_eq!(outputs[0], 1);
 
         let mut program = vec![3, 3, 1108, -1, 8, 3, 4, 3, 99];
-        let outputs = run_intcode(&mut program, 7);
+        let outputs = run_intcode(&mut program, 7).unwrap();
         assert_eq!(outputs[0], 0);
     }
 
@@ -210,11 +238,11 @@ mod tests {
     fn test_less_than_immediate_mode() {
         // Using immediate mode, less than 8
         let mut program = vec![3, 3, 1107, -1, 8, 3, 4, 3, 99];
-        let outputs = run_intcode(&mut program, 7);
+        let outputs = run_intcode(&mut program, 7).unwrap();
         assert_eq!(outputs[0], 1);
 
         let mut program = vec![3, 3, 1107, -1, 8, 3, 4, 3, 99];
-        let outputs = run_intcode(&mut program, 8);
+        let outputs = run_intcode(&mut program, 8).unwrap();
         assert_eq!(outputs[0], 0);
     }
 
@@ -222,11 +250,11 @@ mod tests {
     fn test_jump_position_mode() {
         // Position mode jump test - output 0 if input is 0, 1 otherwise
         let mut program = vec![3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
-        let outputs = run_intcode(&mut program, 0);
+        let outputs = run_intcode(&mut program, 0).unwrap();
         assert_eq!(outputs[0], 0);
 
         let mut program = vec![3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
-        let outputs = run_intcode(&mut program, 5);
+        let outputs = run_intcode(&mut program, 5).unwrap();
         assert_eq!(outputs[0], 1);
     }
 
@@ -234,11 +262,11 @@ mod tests {
     fn test_jump_immediate_mode() {
         // Immediate mode jump test - output 0 if input is 0, 1 otherwise
         let mut program = vec![3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
-        let outputs = run_intcode(&mut program, 0);
+        let outputs = run_intcode(&mut program, 0).unwrap();
         assert_eq!(outputs[0], 0);
 
         let mut program = vec![3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
-        let outputs = run_intcode(&mut program, 5);
+        let outputs = run_intcode(&mut program, 5).unwrap();
         assert_eq!(outputs[0], 1);
     }
 
@@ -247,32 +275,32 @@ mod tests {
         // Output 999 if input < 8, 1000 if input == 8, 1001 if input > 8
         let program_str = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
         let mut program: Vec<i32> = program_str.split(',').map(|x| x.parse().unwrap()).collect();
-        let outputs = run_intcode(&mut program, 7);
+        let outputs = run_intcode(&mut program, 7).unwrap();
         assert_eq!(outputs[0], 999);
 
         let mut program: Vec<i32> = program_str.split(',').map(|x| x.parse().unwrap()).collect();
-        let outputs = run_intcode(&mut program, 8);
+        let outputs = run_intcode(&mut program, 8).unwrap();
         assert_eq!(outputs[0], 1000);
 
         let mut program: Vec<i32> = program_str.split(',').map(|x| x.parse().unwrap()).collect();
-        let outputs = run_intcode(&mut program, 9);
+        let outputs = run_intcode(&mut program, 9).unwrap();
         assert_eq!(outputs[0], 1001);
     }
 
     #[test]
-    fn silver_test_input() {
-        assert_eq!(silver("3,0,4,0,99").to_string(), "1");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2019, 5);
-        assert_eq!(silver(&input).to_string(), "4511442");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2019, 5);
-        assert_eq!(gold(&input).to_string(), "12648139");
+    fn invalid_instructions() {
+        // Outputs its input, then runs into an unknown opcode
+        assert_eq!(
+            silver("3,0,4,0,77,99").unwrap_err(),
+            PuzzleError::parse(1, 9, "unknown opcode 77")
+        );
+        assert_eq!(
+            silver("3,0,4,0,\n299").unwrap_err(),
+            PuzzleError::parse(2, 1, "invalid parameter mode 2")
+        );
+        assert_eq!(
+            gold("3,0,x").unwrap_err(),
+            PuzzleError::parse(1, 5, "invalid value 'x'")
+        );
     }
 }
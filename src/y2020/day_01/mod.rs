@@ -1,7 +1,8 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
+use crate::puzzle_error::PuzzleError;
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut numbers: Vec<i32> = input.lines().map(|s| s.parse().unwrap()).collect();
     numbers.sort_unstable();
 
@@ -9,16 +10,16 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         for j in (i + 1..numbers.len()).rev() {
             let sum = numbers[i] + numbers[j];
             if sum == 2020 {
-                return Box::new(numbers[i] * numbers[j]);
+                return Ok(Answer::from(numbers[i] * numbers[j]));
             } else if sum < 2020 {
                 break;
             }
         }
     }
-    Box::new(0)
+    Err(PuzzleError::NoSolution)
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut numbers: Vec<i32> = input.lines().map(|s| s.parse().unwrap()).collect();
     numbers.sort_unstable();
 
@@ -28,14 +29,14 @@ pub fn gold(input: &str) -> Box<dyn Display> {
             for k in (i + 1..numbers.len()).rev() {
                 let sum = partial_sum + numbers[k];
                 if sum == 2020 {
-                    return Box::new(numbers[i] * numbers[j] * numbers[k]);
+                    return Ok(Answer::from(numbers[i] * numbers[j] * numbers[k]));
                 } else if sum < 2020 {
                     break;
                 }
             }
         }
     }
-    Box::new(0)
+    Err(PuzzleError::NoSolution)
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -45,36 +46,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "1721\n979\n366\n299\n675\n1456";
-        assert_eq!(silver(input).to_string(), "514579");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2020, 1);
-        assert_eq!(silver(&input).to_string(), "918339");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "1721\n979\n366\n299\n675\n1456";
-        assert_eq!(gold(input).to_string(), "241861950");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2020, 1);
-        assert_eq!(gold(&input).to_string(), "23869440");
+    aoc_tests! {
+        2020, 1,
+        example 1 => (silver, gold),
+        actual => ("918339", "23869440"),
     }
 }
@@ -1,15 +1,16 @@
-use crate::aoc_puzzle::{AocPuzzle, PuzzlePart};
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzlePart, PuzzleResult};
+use crate::puzzle_error::PuzzleError;
 use std::collections::HashMap;
-use std::fmt::Display;
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let tiles = parse_tiles(input);
     let result = find_corner_product(&tiles);
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn gold(_input: &str) -> Box<dyn Display> {
-    Box::new(0)
+pub fn gold(_input: &str) -> PuzzleResult {
+    Err(PuzzleError::NotImplemented)
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -120,142 +121,24 @@ fn find_corner_product(tiles: &[Tile]) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::{actual_input, aoc_tests, read_example};
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "Tile 2311:
-..##.#..#.
-##..#.....
-#...##..#.
-####.#...#
-##.##.###.
-##...#.###
-.#.#.#..##
-..#....#..
-###...#.#.
-..###..###
-
-Tile 1951:
-#.##...##.
-#.####...#
-.....#..##
-#...######
-.##.#....#
-.###.#####
-###.##.##.
-.###....#.
-..#.#..#.#
-#...##.#..
-
-Tile 1171:
-####...##.
-#..##.#..#
-##.#..#.#.
-.###.####.
-..###.####
-.##....##.
-.#...####.
-#.##.####.
-####..#...
-.....##...
-
-Tile 1427:
-###.##.#..
-.#..#.##..
-.#.##.#..#
-#.#.#.##.#
-....#...##
-...##..##.
-...#.#####
-.#.####.#.
-..#..###.#
-..##.#..#.
-
-Tile 1489:
-##.#.#....
-..##...#..
-.##..##...
-..#...#...
-#####...#.
-#..#.#.#.#
-...#.#.#..
-##.#...##.
-..##.##.##
-###.##.#..
-
-Tile 2473:
-#....####.
-#..#.##...
-#.##..#...
-######.#.#
-.#...#.#.#
-.#########
-.###.#..#.
-########.#
-##...##.#.
-..###.#.#.
-
-Tile 2971:
-..#.#....#
-#...###...
-#.#.###...
-##.##..#..
-.#####..##
-.#..####.#
-#..#.#..#.
-..####.###
-..#.#.###.
-...#.#.#.#
-
-Tile 2729:
-...#.#.#.#
-####.#....
-..#.#.....
-....#..#.#
-.##..##.#.
-.#.####...
-####.#.#..
-##.####...
-##..#.##..
-#.##...##.
-
-Tile 3079:
-#.#.#####.
-.#..######
-..#.......
-######....
-####.#..#.
-.#...#.##.
-#.#####.##
-..#.###...
-..#.......
-..#.###...";
-        assert_eq!(silver(input).to_string(), "20899048083289");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2020, 20);
-        assert_eq!(silver(&input).to_string(), "8425574315321");
+    aoc_tests! {
+        2020, 20,
+        example 1 => (silver, _),
+        actual => ("8425574315321", _),
     }
 
     #[test]
     fn gold_test_input() {
-        let input = "test";
-        assert_eq!(gold(input).to_string(), "0");
+        assert!(matches!(gold(&read_example(2020, 20, 1)), Err(PuzzleError::NotImplemented)));
     }
 
     #[test]
     fn gold_actual_input() {
-        let input = read_input(2020, 20);
-        assert_eq!(gold(&input).to_string(), "0");
+        let Some(input) = actual_input(2020, 20) else {
+            return;
+        };
+        assert!(matches!(gold(&input), Err(PuzzleError::NotImplemented)));
     }
 }
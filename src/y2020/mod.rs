@@ -1,12 +0,0 @@
-mod day_01;
-mod day_20;
-
-use crate::aoc_puzzle::AocPuzzle;
-use std::sync::LazyLock;
-
-static PUZZLES: LazyLock<Vec<AocPuzzle>> =
-    LazyLock::new(|| vec![day_01::puzzle(), day_20::puzzle()]);
-
-pub fn puzzles() -> &'static Vec<AocPuzzle> {
-    &PUZZLES
-}
@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut h_pos = 0;
     let mut v_pos = 0;
 
@@ -18,10 +18,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(h_pos * v_pos)
+    Ok(Answer::from(h_pos * v_pos))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut h_pos = 0;
     let mut v_pos = 0;
     let mut aim = 0;
@@ -42,7 +42,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(h_pos * v_pos)
+    Ok(Answer::from(h_pos * v_pos))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -52,46 +52,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "forward 5
-down 5
-forward 8
-up 3
-down 8
-forward 2";
-        assert_eq!(silver(input).to_string(), "150");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 2);
-        assert_eq!(silver(&input).to_string(), "2272262");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "forward 5
-down 5
-forward 8
-up 3
-down 8
-forward 2";
-        assert_eq!(gold(input).to_string(), "900");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 2);
-        assert_eq!(gold(&input).to_string(), "2134882034");
+    aoc_tests! {
+        2021, 2,
+        example 1 => (silver, gold),
+        actual => ("2272262", "2134882034"),
     }
 }
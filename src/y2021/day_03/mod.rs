@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let numbers: Vec<&str> = input.lines().collect();
     let bit_length = numbers[0].len();
     let mut bit_count = vec![0; bit_length];
@@ -32,10 +32,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
     let gamma_value = i32::from_str_radix(&gamma, 2).unwrap();
     let epsilon_value = i32::from_str_radix(&epsilon, 2).unwrap();
 
-    Box::new(gamma_value * epsilon_value)
+    Ok(Answer::from(gamma_value * epsilon_value))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let digits: Vec<&str> = input.lines().collect();
     let bit_length = digits[0].len();
 
@@ -75,7 +75,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
     let oxygen_value = i32::from_str_radix(oxygen_rating[0], 2).unwrap();
     let co2_value = i32::from_str_radix(co2_rating[0], 2).unwrap();
 
-    Box::new(oxygen_value * co2_value)
+    Ok(Answer::from(oxygen_value * co2_value))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -85,58 +85,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "00100
-11110
-10110
-10111
-10101
-01111
-00111
-11100
-10000
-11001
-00010
-01010";
-        assert_eq!(silver(input).to_string(), "198");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 3);
-        assert_eq!(silver(&input).to_string(), "4139586");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "00100
-11110
-10110
-10111
-10101
-01111
-00111
-11100
-10000
-11001
-00010
-01010";
-        assert_eq!(gold(input).to_string(), "230");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 3);
-        assert_eq!(gold(&input).to_string(), "1800151");
+    aoc_tests! {
+        2021, 3,
+        example 1 => (silver, gold),
+        actual => ("4139586", "1800151"),
     }
 }
@@ -1,16 +1,16 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let (mut boards, numbers) = parse_input(input);
     let (winning_board, num) = mark_and_test_boards(&mut boards, &numbers);
-    Box::new(score_board(&winning_board, num))
+    Ok(Answer::from(score_board(&winning_board, num)))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let (boards, numbers) = parse_input(input);
     let (winning_board, num) = get_last_winning_board(boards, &numbers);
-    Box::new(score_board(&winning_board, num))
+    Ok(Answer::from(score_board(&winning_board, num)))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -107,72 +107,11 @@ fn score_board(board: &[Vec<i32>], last_num: i32) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11  0
- 8  2 23  4 24
-21  9 14 16  7
- 6 10  3 18  5
- 1 12 20 15 19
-
- 3 15  0  2 22
- 9 18 13 17  5
-19  8  7 25 23
-20 11 10 24  4
-14 21 16 12  6
-
-14 21 17 24  4
-10 16 15  9 19
-18  8 23 26 20
-22 11 13  6  5
- 2  0 12  3  7";
-        assert_eq!(silver(input).to_string(), "4512");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 4);
-        assert_eq!(silver(&input).to_string(), "51034");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11  0
- 8  2 23  4 24
-21  9 14 16  7
- 6 10  3 18  5
- 1 12 20 15 19
-
- 3 15  0  2 22
- 9 18 13 17  5
-19  8  7 25 23
-20 11 10 24  4
-14 21 16 12  6
-
-14 21 17 24  4
-10 16 15  9 19
-18  8 23 26 20
-22 11 13  6  5
- 2  0 12  3  7";
-        assert_eq!(gold(input).to_string(), "1924");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 4);
-        assert_eq!(gold(&input).to_string(), "5434");
+    aoc_tests! {
+        2021, 4,
+        example 1 => (silver, gold),
+        actual => ("51034", "5434"),
     }
 }
@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut m = vec![vec![0u16; 1000]; 1000];
 
     for line in input.lines() {
@@ -34,10 +34,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         .filter(|&&x| x > 1)
         .count();
 
-    Box::new(count)
+    Ok(Answer::from(count))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut m = vec![vec![0u16; 1000]; 1000];
 
     for line in input.lines() {
@@ -78,7 +78,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         .filter(|&&x| x > 1)
         .count();
 
-    Box::new(count)
+    Ok(Answer::from(count))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -88,54 +88,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "0,9 -> 5,9
-8,0 -> 0,8
-9,4 -> 3,4
-2,2 -> 2,1
-7,0 -> 7,4
-6,4 -> 2,0
-0,9 -> 2,9
-3,4 -> 1,4
-0,0 -> 8,8
-5,5 -> 8,2";
-        assert_eq!(silver(input).to_string(), "5");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 5);
-        assert_eq!(silver(&input).to_string(), "5197");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "0,9 -> 5,9
-8,0 -> 0,8
-9,4 -> 3,4
-2,2 -> 2,1
-7,0 -> 7,4
-6,4 -> 2,0
-0,9 -> 2,9
-3,4 -> 1,4
-0,0 -> 8,8
-5,5 -> 8,2";
-        assert_eq!(gold(input).to_string(), "12");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 5);
-        assert_eq!(gold(&input).to_string(), "18605");
+    aoc_tests! {
+        2021, 5,
+        example 1 => (silver, gold),
+        actual => ("5197", "18605"),
     }
 }
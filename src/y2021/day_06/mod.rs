@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut timers: Vec<u8> = input.split(',').map(|s| s.parse().unwrap()).collect();
 
     for _t in 0..80 {
@@ -17,10 +17,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         timers.extend(offspring);
     }
 
-    Box::new(timers.len())
+    Ok(Answer::from(timers.len()))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let timers: Vec<usize> = input.split(',').map(|s| s.parse().unwrap()).collect();
 
     // Count fish by timer value (0-8)
@@ -44,7 +44,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         fish[8] = gen1;
     }
 
-    Box::new(fish.iter().sum::<u64>())
+    Ok(Answer::from(fish.iter().sum::<u64>()))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -54,34 +54,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver("3,4,3,1,2").to_string(), "5934");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 6);
-        assert_eq!(silver(&input).to_string(), "373378");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold("3,4,3,1,2").to_string(), "26984457539");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 6);
-        assert_eq!(gold(&input).to_string(), "1682576647495");
+    aoc_tests! {
+        2021, 6,
+        example 1 => (silver, gold),
+        actual => ("373378", "1682576647495"),
     }
 }
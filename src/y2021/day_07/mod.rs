@@ -1,12 +1,12 @@
-use crate::aoc_puzzle::AocPuzzle;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 use std::collections::HashSet;
-use std::fmt::Display;
 
 fn triangular(n: i32) -> i32 {
     n * (n + 1) / 2
 }
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let crabs: Vec<i32> = input.split(',').map(|s| s.parse().unwrap()).collect();
     let positions: Vec<i32> = {
         let mut unique: Vec<i32> = crabs
@@ -39,10 +39,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(total_cost)
+    Ok(Answer::from(total_cost))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let crabs: Vec<i32> = input.split(',').map(|s| s.parse().unwrap()).collect();
     let positions: Vec<i32> = {
         let mut unique: Vec<i32> = crabs
@@ -66,7 +66,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(least_cost)
+    Ok(Answer::from(least_cost))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -76,34 +76,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver("16,1,2,0,4,2,7,1,2,14").to_string(), "37");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 7);
-        assert_eq!(silver(&input).to_string(), "355592");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold("16,1,2,0,4,2,7,1,2,14").to_string(), "168");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 7);
-        assert_eq!(gold(&input).to_string(), "101618069");
+    aoc_tests! {
+        2021, 7,
+        example 1 => (silver, gold),
+        actual => ("355592", "101618069"),
     }
 }
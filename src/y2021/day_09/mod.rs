@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let heightmap = parse_heightmap(input);
     let mut total_risk_level = 0;
 
@@ -16,10 +16,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(total_risk_level)
+    Ok(Answer::from(total_risk_level))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let heightmap = parse_heightmap(input);
     let mut lowpoints = Vec::new();
 
@@ -41,7 +41,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
 
     basins.sort_by(|a, b| b.cmp(a));
 
-    Box::new(basins[0] * basins[1] * basins[2])
+    Ok(Answer::from(basins[0] * basins[1] * basins[2]))
 }
 
 fn parse_heightmap(input: &str) -> Vec<Vec<i32>> {
@@ -128,44 +128,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "2199943210
-3987894921
-9856789892
-8767896789
-9899965678";
-        assert_eq!(silver(input).to_string(), "15");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 9);
-        assert_eq!(silver(&input).to_string(), "500");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "2199943210
-3987894921
-9856789892
-8767896789
-9899965678";
-        assert_eq!(gold(input).to_string(), "1134");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 9);
-        assert_eq!(gold(&input).to_string(), "970200");
+    aoc_tests! {
+        2021, 9,
+        example 1 => (silver, gold),
+        actual => ("500", "970200"),
     }
 }
@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut error_score = 0;
 
     for line in input.lines() {
@@ -29,10 +29,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(error_score)
+    Ok(Answer::from(error_score))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut autocomplete_scores = Vec::new();
 
     for line in input.lines() {
@@ -74,7 +74,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
 
     autocomplete_scores.sort();
     let result = autocomplete_scores[autocomplete_scores.len() / 2];
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -84,45 +84,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    const TEST_INPUT: &str = "[({(<(())[]>[[{[]{<()<>>
-[(()[<>])]({[<{<<[]>>(
-{([(<{}[<>[]}>{[]{[(<()>
-(((({<>}<{<{<>}{[]{[]{}
-[[<[([]))<([[{}[[()]]]
-[{[{({}]{}}([{[{{{}}([]
-{<[[]]>}<{[{[{[]{()[[[]
-[<(<(<(<{}))><([]([]()
-<{([([[(<>()){}]>(<<{{
-<{([{{}}[<[[[<>{}]]]>[]]";
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(TEST_INPUT).to_string(), "26397");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 10);
-        assert_eq!(silver(&input).to_string(), "299793");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(TEST_INPUT).to_string(), "288957");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 10);
-        assert_eq!(gold(&input).to_string(), "3654963618");
+    aoc_tests! {
+        2021, 10,
+        example 1 => (silver, gold),
+        actual => ("299793", "3654963618"),
     }
 }
@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut octopuses: Vec<Vec<u32>> = input
         .lines()
         .map(|r| r.chars().map(|c| c.to_digit(10).unwrap()).collect())
@@ -21,10 +21,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
             .collect();
     }
 
-    Box::new(flash_count)
+    Ok(Answer::from(flash_count))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut octopuses: Vec<Vec<u32>> = input
         .lines()
         .map(|r| r.chars().map(|c| c.to_digit(10).unwrap()).collect())
@@ -40,7 +40,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         let flash_count = step(&mut octopuses);
         step_count += 1;
         if flash_count == 100 {
-            return Box::new(step_count);
+            return Ok(Answer::from(step_count));
         }
         octopuses = octopuses
             .iter()
@@ -123,36 +123,11 @@ fn increment(m: &mut Vec<Vec<u32>>, r: usize, c: usize) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526";
-        assert_eq!(silver(input).to_string(), "1656");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 11);
-        assert_eq!(silver(&input).to_string(), "1661");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526";
-        assert_eq!(gold(input).to_string(), "195");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 11);
-        assert_eq!(gold(&input).to_string(), "334");
+    aoc_tests! {
+        2021, 11,
+        example 1 => (silver, gold),
+        actual => ("1661", "334"),
     }
 }
@@ -1,8 +1,8 @@
-use crate::aoc_puzzle::AocPuzzle;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 use std::collections::HashSet;
-use std::fmt::Display;
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut edges = Vec::new();
 
     for line in input.lines() {
@@ -25,10 +25,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         get_valid_destinations_silver,
     );
 
-    Box::new(paths.len())
+    Ok(Answer::from(paths.len()))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut edges = Vec::new();
 
     for line in input.lines() {
@@ -51,7 +51,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         get_valid_destinations_gold,
     );
 
-    Box::new(paths.len())
+    Ok(Answer::from(paths.len()))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -118,50 +118,12 @@ fn get_paths_from(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end";
-        assert_eq!(silver(input).to_string(), "10");
-    }
-
-    #[test]
-    fn silver_test_input_larger() {
-        let input =
-            "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc";
-        assert_eq!(silver(input).to_string(), "19");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 12);
-        assert_eq!(silver(&input).to_string(), "3292");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end";
-        assert_eq!(gold(input).to_string(), "36");
-    }
-
-    #[test]
-    fn gold_test_input_larger() {
-        let input =
-            "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc";
-        assert_eq!(gold(input).to_string(), "103");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 12);
-        assert_eq!(gold(&input).to_string(), "89592");
+    aoc_tests! {
+        2021, 12,
+        example 1 => (silver, gold),
+        example 2 => (silver, gold),
+        actual => ("3292", "89592"),
     }
 }
@@ -1,6 +1,6 @@
-use crate::aoc_puzzle::AocPuzzle;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 use std::collections::HashSet;
-use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Point {
@@ -8,13 +8,13 @@ struct Point {
     y: i32,
 }
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let (marks, folds) = parse_input(input);
     let folded_marks = fold(&marks, &folds[0]);
-    Box::new(folded_marks.len())
+    Ok(Answer::from(folded_marks.len()))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let (mut marks, folds) = parse_input(input);
 
     for fold_instruction in folds {
@@ -22,7 +22,26 @@ pub fn gold(input: &str) -> Box<dyn Display> {
     }
 
     let plot_string = decode_letters(&marks);
-    Box::new(plot_string)
+    Ok(Answer::art(plot(&marks), plot_string))
+}
+
+fn plot(marks: &HashSet<Point>) -> String {
+    let width = marks.iter().map(|p| p.x).max().unwrap_or(-1) + 1;
+    let height = marks.iter().map(|p| p.y).max().unwrap_or(-1) + 1;
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    if marks.contains(&Point { x, y }) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 fn fold(marks: &HashSet<Point>, fold_at: &str) -> HashSet<Point> {
@@ -142,77 +161,31 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::{actual_input, aoc_tests, read_example};
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "6,10
-0,14
-9,10
-0,3
-10,4
-4,11
-6,0
-6,12
-4,1
-0,13
-10,12
-3,4
-3,0
-8,4
-1,10
-2,14
-8,10
-9,0
-
-fold along y=7
-fold along x=5";
-        assert_eq!(silver(input).to_string(), "17");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 13);
-        assert_eq!(silver(&input).to_string(), "653");
+    aoc_tests! {
+        2021, 13,
+        example 1 => (silver, _),
+        actual => ("653", "LKREBPRK"),
     }
 
     #[test]
     fn gold_test_input() {
-        let input = "6,10
-0,14
-9,10
-0,3
-10,4
-4,11
-6,0
-6,12
-4,1
-0,13
-10,12
-3,4
-3,0
-8,4
-1,10
-2,14
-8,10
-9,0
-
-fold along y=7
-fold along x=5";
-        // The test input forms a square pattern, not letters
-        assert_eq!(gold(input).to_string().len() > 0, true);
+        // The example forms a square pattern, not letters
+        assert!(!gold(&read_example(2021, 13, 1))
+            .unwrap()
+            .to_string()
+            .is_empty());
     }
 
     #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 13);
-        assert_eq!(gold(&input).to_string(), "LKREBPRK");
+    fn gold_draws_the_letters() {
+        let Some(input) = actual_input(2021, 13) else {
+            return;
+        };
+        let answer = gold(&input).unwrap();
+        let picture = answer.picture_lines();
+        assert_eq!(picture.len(), 6);
+        assert!(picture[0].starts_with("#....#..#.###..####"));
     }
 }
@@ -1,8 +1,8 @@
-use crate::aoc_puzzle::AocPuzzle;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 use std::collections::HashMap;
-use std::fmt::Display;
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let sections: Vec<&str> = input.split("\n\n").collect();
     let template = sections[0];
 
@@ -29,10 +29,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
     let min = *counts.iter().min().unwrap();
     let max = *counts.iter().max().unwrap();
 
-    Box::new(max - min)
+    Ok(Answer::from(max - min))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let sections: Vec<&str> = input.split("\n\n").collect();
     let template = sections[0];
 
@@ -91,7 +91,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
     let min = *counts.iter().min().unwrap();
     let max = *counts.iter().max().unwrap();
 
-    Box::new(max - min)
+    Ok(Answer::from(max - min))
 }
 
 fn count_silver(pair: &str, rules: &[(&str, &str)], counter: &mut HashMap<char, i64>, steps: i32) {
@@ -156,70 +156,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let test_input = "NNCB
-
-CH -> B
-HH -> N
-CB -> H
-NH -> C
-HB -> C
-HC -> B
-HN -> C
-NN -> C
-BH -> H
-NC -> B
-NB -> B
-BN -> B
-BB -> N
-BC -> B
-CC -> N
-CN -> C";
-        assert_eq!(silver(test_input).to_string(), "1588");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 14);
-        assert_eq!(silver(&input).to_string(), "4244");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let test_input = "NNCB
-
-CH -> B
-HH -> N
-CB -> H
-NH -> C
-HB -> C
-HC -> B
-HN -> C
-NN -> C
-BH -> H
-NC -> B
-NB -> B
-BN -> B
-BB -> N
-BC -> B
-CC -> N
-CN -> C";
-        assert_eq!(gold(test_input).to_string(), "2188189693529");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 14);
-        assert_eq!(gold(&input).to_string(), "4807056953866");
+    aoc_tests! {
+        2021, 14,
+        example 1 => (silver, gold),
+        actual => ("4244", "4807056953866"),
     }
 }
@@ -1,5 +1,5 @@
-use crate::aoc_puzzle::{AocPuzzle, PuzzlePart};
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzlePart, PuzzleResult};
 
 #[derive(Debug, Clone)]
 struct Tile {
@@ -104,7 +104,7 @@ fn next_val(val: u32, increment: u32) -> u32 {
     }
 }
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let values: Vec<Vec<u32>> = input
         .lines()
         .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
@@ -155,10 +155,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(m[max_row][max_col].sum - m[0][0].sum)
+    Ok(Answer::from(m[max_row][max_col].sum - m[0][0].sum))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let template: Vec<Vec<u32>> = input
         .lines()
         .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
@@ -228,59 +228,46 @@ pub fn gold(input: &str) -> Box<dyn Display> {
     }
 
     let start_val = m[0][0].sum;
-    Box::new(m[max_row][max_col].sum - start_val)
+    Ok(Answer::from(m[max_row][max_col].sum - start_val))
 }
 
 pub fn puzzle() -> AocPuzzle {
     AocPuzzle::new(2021, 15, silver, gold)
-        .skip(PuzzlePart::Gold, "Very slow - takes ~3 minutes")
-        .skip(PuzzlePart::Both, "Very slow - takes ~3 minutes")
+        .skip_unless_timeout(
+            PuzzlePart::Gold,
+            "Very slow - takes ~3 minutes, run with --timeout",
+        )
+        .skip_unless_timeout(
+            PuzzlePart::Both,
+            "Very slow - takes ~3 minutes, run with --timeout",
+        )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::{actual_input, aoc_tests};
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    const TEST_INPUT: &str = "1163751742
-1381373672
-2136511328
-3694931569
-7463417111
-1319128137
-1359912421
-3125421639
-1293138521
-2311944581";
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(TEST_INPUT).to_string(), "40");
+    aoc_tests! {
+        2021, 15,
+        example 1 => (silver, gold),
     }
 
     #[test]
     #[ignore] // Slow - takes ~0.18s in release mode
     fn silver_actual_input() {
-        let input = read_input(2021, 15);
-        assert_eq!(silver(&input).to_string(), "609");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(TEST_INPUT).to_string(), "315");
+        let Some(input) = actual_input(2021, 15) else {
+            return;
+        };
+        assert_eq!(silver(&input).unwrap().to_string(), "609");
     }
 
     #[test]
     #[ignore] // Very slow - takes ~3+ minutes in release mode
     fn gold_actual_input() {
-        let input = read_input(2021, 15);
-        assert_eq!(gold(&input).to_string(), "2925");
+        let Some(input) = actual_input(2021, 15) else {
+            return;
+        };
+        assert_eq!(gold(&input).unwrap().to_string(), "2925");
     }
 }
@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let bits: String = input
         .chars()
         .map(|hex| to_binary(hex))
@@ -9,10 +9,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         .join("");
 
     let packet = parse_packet_at(&bits, 0);
-    Box::new(packet.packet_version_sum)
+    Ok(Answer::from(packet.packet_version_sum))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let bits: String = input
         .chars()
         .map(|hex| to_binary(hex))
@@ -20,7 +20,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         .join("");
 
     let packet = parse_packet_at(&bits, 0);
-    Box::new(packet.value)
+    Ok(Answer::from(packet.value))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -142,44 +142,22 @@ fn parse_packet_at(s: &str, idx: usize) -> Packet {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
-
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver("8A004A801A8002F478").to_string(), "16");
-        assert_eq!(silver("620080001611562C8802118E34").to_string(), "12");
-        assert_eq!(silver("C0015000016115A2E0802F182340").to_string(), "23");
-        assert_eq!(silver("A0016C880162017C3686B18A3D4780").to_string(), "31");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 16);
-        assert_eq!(silver(&input).to_string(), "871");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold("C200B40A82").to_string(), "3");
-        assert_eq!(gold("04005AC33890").to_string(), "54");
-        assert_eq!(gold("880086C3E88112").to_string(), "7");
-        assert_eq!(gold("CE00C43D881120").to_string(), "9");
-        assert_eq!(gold("D8005AC2A8F0").to_string(), "1");
-        assert_eq!(gold("F600BC2D8F").to_string(), "0");
-        assert_eq!(gold("9C005AC2F8F0").to_string(), "0");
-        assert_eq!(gold("9C0141080250320F1802104A08").to_string(), "1");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 16);
-        assert_eq!(gold(&input).to_string(), "68703010504");
+    use crate::test_support::aoc_tests;
+
+    aoc_tests! {
+        2021, 16,
+        example 1 => (silver, _),
+        example 2 => (silver, _),
+        example 3 => (silver, _),
+        example 4 => (silver, _),
+        example 5 => (_, gold),
+        example 6 => (_, gold),
+        example 7 => (_, gold),
+        example 8 => (_, gold),
+        example 9 => (_, gold),
+        example 10 => (_, gold),
+        example 11 => (_, gold),
+        example 12 => (_, gold),
+        actual => ("871", "68703010504"),
     }
 }
@@ -1,5 +1,5 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
 // These magic numbers were found via trial and error to limit the search space
 // Will not work for all inputs!
@@ -8,7 +8,7 @@ const MAGIC_NUMBER_MAX_X: i32 = 50;
 const MAGIC_NUMBER_MIN_Y: i32 = 0;
 const MAGIC_NUMBER_MAX_Y: i32 = 400;
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let target_area = parse_target_area(input);
     let mut max_y_pos = i32::MIN;
     let mut y = MAGIC_NUMBER_MIN_Y;
@@ -24,10 +24,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         y += 1;
     }
 
-    Box::new(max_y_pos)
+    Ok(Answer::from(max_y_pos))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let target_area = parse_target_area(input);
     let mut hits = 0;
 
@@ -39,7 +39,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(hits)
+    Ok(Answer::from(hits))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -105,34 +105,11 @@ fn parse_target_area(input: &str) -> TargetArea {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver("target area: x=20..30, y=-10..-5").to_string(), "45");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 17);
-        assert_eq!(silver(&input).to_string(), "6555");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold("target area: x=20..30, y=-10..-5").to_string(), "112");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 17);
-        assert_eq!(gold(&input).to_string(), "4973");
+    aoc_tests! {
+        2021, 17,
+        example 1 => (silver, gold),
+        actual => ("6555", "4973"),
     }
 }
@@ -1,16 +1,16 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let numbers: Vec<&str> = input.lines().collect();
     let mut sum = numbers[0].to_string();
     for i in 1..numbers.len() {
         sum = add(&sum, numbers[i]);
     }
-    Box::new(magnitude(&parse_pairs(&sum)))
+    Ok(Answer::from(magnitude(&parse_pairs(&sum))))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let numbers: Vec<&str> = input.lines().collect();
     let mut sums = Vec::new();
     for i in 1..numbers.len() {
@@ -24,7 +24,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         .map(|s| magnitude(&parse_pairs(s)))
         .max()
         .unwrap();
-    Box::new(max_magnitude)
+    Ok(Answer::from(max_magnitude))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -186,13 +186,12 @@ fn get_closing_index(n: &str, start: usize) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
+    aoc_tests! {
+        2021, 18,
+        example 1 => (silver, gold),
+        actual => ("3884", "4595"),
     }
 
     #[test]
@@ -220,46 +219,4 @@ mod tests {
         let result = add("[[[[4,3],4],4],[7,[[8,4],9]]]", "[1,1]");
         assert_eq!(result, "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
     }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-[[[5,[2,8]],4],[5,[[9,9],0]]]
-[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-[[[[5,4],[7,7]],8],[[8,3],8]]
-[[9,3],[[9,9],[6,[4,9]]]]
-[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
-        assert_eq!(silver(input).to_string(), "4140");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2021, 18);
-        assert_eq!(silver(&input).to_string(), "3884");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-[[[5,[2,8]],4],[5,[[9,9],0]]]
-[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-[[[[5,4],[7,7]],8],[[8,3],8]]
-[[9,3],[[9,9],[6,[4,9]]]]
-[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
-        assert_eq!(gold(input).to_string(), "3993");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2021, 18);
-        assert_eq!(gold(&input).to_string(), "4595");
-    }
 }
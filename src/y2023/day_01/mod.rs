@@ -1,20 +1,20 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let result: u32 = input
         .lines()
         .map(|line| first_digit(line) * 10 + last_digit(line))
         .sum();
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let result: u32 = input
         .lines()
         .map(|line| first_digit_or_spelled(line) * 10 + last_digit_or_spelled(line))
         .sum();
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -91,36 +91,12 @@ fn last_digit_or_spelled(txt: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
-        assert_eq!(silver(input).to_string(), "142");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2023, 1);
-        assert_eq!(silver(&input).to_string(), "54877");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
-        assert_eq!(gold(input).to_string(), "281");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2023, 1);
-        assert_eq!(gold(&input).to_string(), "54100");
+    aoc_tests! {
+        2023, 1,
+        example 1 => (silver, _),
+        example 2 => (_, gold),
+        actual => ("54877", "54100"),
     }
 }
@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let (mut list1, mut list2) = parse_lists(input);
     list1.sort_unstable();
     list2.sort_unstable();
@@ -12,10 +12,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         .map(|(a, b)| (a - b).abs())
         .sum();
 
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let (list1, list2) = parse_lists(input);
 
     let result: i32 = list1
@@ -23,7 +23,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         .map(|&x| x * count_occurrences(&list2, x))
         .sum();
 
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -49,46 +49,11 @@ fn count_occurrences(list: &[i32], value: i32) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3";
-        assert_eq!(silver(input).to_string(), "11");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2024, 1);
-        assert_eq!(silver(&input).to_string(), "2430334");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3";
-        assert_eq!(gold(input).to_string(), "31");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2024, 1);
-        assert_eq!(gold(&input).to_string(), "28786472");
+    aoc_tests! {
+        2024, 1,
+        example 1 => (silver, gold),
+        actual => ("2430334", "28786472"),
     }
 }
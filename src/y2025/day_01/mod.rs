@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut count_zeros = 0;
     let mut position = 50;
 
@@ -11,10 +11,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
             count_zeros += 1;
         }
     }
-    Box::new(count_zeros)
+    Ok(Answer::from(count_zeros))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut count_zeros = 0;
     let mut position = 50;
 
@@ -23,10 +23,10 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         count_zeros += result.rotations;
         position = result.new_position;
     }
-    Box::new(count_zeros)
+    Ok(Answer::from(count_zeros))
 }
 
-pub fn both(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
+pub fn both(input: &str) -> PuzzleResult<(Answer, Answer)> {
     let mut count_zeros_silver = 0;
     let mut count_zeros_gold = 0;
     let mut position = 50;
@@ -39,7 +39,7 @@ pub fn both(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
             count_zeros_silver += 1;
         }
     }
-    (Box::new(count_zeros_silver), Box::new(count_zeros_gold))
+    Ok((Answer::from(count_zeros_silver), Answer::from(count_zeros_gold)))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -97,36 +97,11 @@ fn rotate_and_count_rotations(instruction: &str, from_position: i32) -> Rotation
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    const TEST_INPUT: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(TEST_INPUT).to_string(), "3");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 1);
-        assert_eq!(silver(&input).to_string(), "999");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(TEST_INPUT).to_string(), "6");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2025, 1);
-        assert_eq!(gold(&input).to_string(), "6099");
+    aoc_tests! {
+        2025, 1,
+        example 1 => (silver, gold),
+        actual => ("999", "6099"),
     }
 }
@@ -1,12 +1,12 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
-    Box::new(sum_invalid_ids(input, true))
+pub fn silver(input: &str) -> PuzzleResult {
+    Ok(Answer::from(sum_invalid_ids(input, true)))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
-    Box::new(sum_invalid_ids(input, false))
+pub fn gold(input: &str) -> PuzzleResult {
+    Ok(Answer::from(sum_invalid_ids(input, false)))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -77,50 +77,12 @@ fn construct_number(repeated_part: i64, count: i32) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver("10-12").to_string(), "11");
-    }
-
-    #[test]
-    fn silver_test_input_extended() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
-            1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
-            824824821-824824827,2121212118-2121212124";
-        assert_eq!(silver(input).to_string(), "1227775554");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 2);
-        assert_eq!(silver(&input).to_string(), "24043483400");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold("10-12").to_string(), "11");
-    }
-
-    #[test]
-    fn gold_test_input_extended() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
-            1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
-            824824821-824824827,2121212118-2121212124";
-        assert_eq!(gold(input).to_string(), "4174379265");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2025, 2);
-        assert_eq!(gold(&input).to_string(), "38262920235");
+    aoc_tests! {
+        2025, 2,
+        example 1 => (silver, gold),
+        example 2 => (silver, gold),
+        actual => ("24043483400", "38262920235"),
     }
 }
@@ -1,15 +1,15 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let result: i64 = input
         .lines()
         .map(|bank| max_joltage_rating(bank))
         .sum();
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let result: i64 = input
         .lines()
         .map(|bank| {
@@ -22,7 +22,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
                 .unwrap()
         })
         .sum();
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -74,39 +74,11 @@ fn max_joltage_rating_remaining(batteries: &[u8], remaining: usize, ratings: Str
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    const TEST_INPUT: &str = "987654321111111
-811111111111119
-234234234234278
-818181911112111";
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(TEST_INPUT).to_string(), "357");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 3);
-        assert_eq!(silver(&input).to_string(), "16842");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(TEST_INPUT).to_string(), "3121910778619");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2025, 3);
-        assert_eq!(gold(&input).to_string(), "167523425665348");
+    aoc_tests! {
+        2025, 3,
+        example 1 => (silver, gold),
+        actual => ("16842", "167523425665348"),
     }
 }
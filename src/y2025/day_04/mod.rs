@@ -1,5 +1,5 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
 const ADJACENT_DELTAS: [(i32, i32); 8] = [
     (-1, -1), (-1, 0), (-1, 1),
@@ -7,7 +7,7 @@ const ADJACENT_DELTAS: [(i32, i32); 8] = [
     (1, -1),  (1, 0),  (1, 1),
 ];
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let lines: Vec<&str> = input.lines().collect();
     let num_rows = lines.len();
     let num_cols = lines[0].len();
@@ -39,15 +39,15 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(count)
+    Ok(Answer::from(count))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
-    let result = both(input);
-    Box::new(result.1)
+pub fn gold(input: &str) -> PuzzleResult {
+    let (_, result) = both(input)?;
+    Ok(result)
 }
 
-pub fn both(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
+pub fn both(input: &str) -> PuzzleResult<(Answer, Answer)> {
     let lines: Vec<&str> = input.lines().collect();
     let num_rows = lines.len();
     let num_cols = lines[0].len();
@@ -112,7 +112,7 @@ pub fn both(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
         }
     }
 
-    (Box::new(silver_count), Box::new(gold_count))
+    Ok((Answer::from(silver_count), Answer::from(gold_count)))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -122,45 +122,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    const TEST_INPUT: &str = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(TEST_INPUT).to_string(), "13");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 4);
-        assert_eq!(silver(&input).to_string(), "1372");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(TEST_INPUT).to_string(), "43");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2025, 4);
-        assert_eq!(gold(&input).to_string(), "7922");
+    aoc_tests! {
+        2025, 4,
+        example 1 => (silver, gold),
+        actual => ("1372", "7922"),
     }
 }
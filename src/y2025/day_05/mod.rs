@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let sections: Vec<&str> = input.split("\n\n").collect();
     let range_section = sections[0];
     let id_section = sections[1];
@@ -37,10 +37,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(count)
+    Ok(Answer::from(count))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let range_section = input.split("\n\n").next().unwrap();
 
     let mut ranges: Vec<[i64; 2]> = range_section
@@ -65,10 +65,10 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(count)
+    Ok(Answer::from(count))
 }
 
-pub fn both(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
+pub fn both(input: &str) -> PuzzleResult<(Answer, Answer)> {
     let sections: Vec<&str> = input.split("\n\n").collect();
     let range_section = sections[0];
     let id_section = sections[1];
@@ -113,7 +113,7 @@ pub fn both(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
         }
     }
 
-    (Box::new(silver_count), Box::new(gold_count))
+    Ok((Answer::from(silver_count), Answer::from(gold_count)))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -123,46 +123,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    const TEST_INPUT: &str = "3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32";
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(TEST_INPUT).to_string(), "3");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 5);
-        assert_eq!(silver(&input).to_string(), "615");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(TEST_INPUT).to_string(), "14");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2025, 5);
-        assert_eq!(gold(&input).to_string(), "353716783056994");
+    aoc_tests! {
+        2025, 5,
+        example 1 => (silver, gold),
+        actual => ("615", "353716783056994"),
     }
 }
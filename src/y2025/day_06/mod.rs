@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let lines: Vec<Vec<&str>> = input
         .lines()
         .map(|line| line.split(' ').filter(|x| !x.is_empty()).collect())
@@ -24,10 +24,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let grid: Vec<Vec<char>> = input
         .lines()
         .map(|line| line.chars().collect())
@@ -75,7 +75,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -85,37 +85,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
-
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end_matches('\n')
-            .trim_end_matches('\r')
-            .to_string()
-    }
-
-    #[test]
-    fn silver_test_input() {
-        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
-        assert_eq!(silver(input).to_string(), "4277556");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 6);
-        assert_eq!(silver(&input).to_string(), "4405895212738");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
-        assert_eq!(gold(input).to_string(), "3263827");
-    }
+    use crate::test_support::aoc_tests;
 
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2025, 6);
-        assert_eq!(gold(&input).to_string(), "7450962489289");
+    aoc_tests! {
+        2025, 6,
+        example 1 => (silver, gold),
+        actual => ("4405895212738", "7450962489289"),
     }
 }
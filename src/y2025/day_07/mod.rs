@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let mut grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
     let mut split_count = 0;
 
@@ -20,10 +20,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
         }
     }
 
-    Box::new(split_count)
+    Ok(Answer::from(split_count))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let mut grid: Vec<Vec<i64>> = input
         .lines()
         .map(|line| {
@@ -52,7 +52,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
     }
 
     let result: i64 = grid.last().unwrap().iter().sum();
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -62,51 +62,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    const TEST_INPUT: &str = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(TEST_INPUT).to_string(), "21");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 7);
-        assert_eq!(silver(&input).to_string(), "1581");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(TEST_INPUT).to_string(), "40");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2025, 7);
-        assert_eq!(gold(&input).to_string(), "73007003089792");
+    aoc_tests! {
+        2025, 7,
+        example 1 => (silver, gold),
+        actual => ("1581", "73007003089792"),
     }
 }
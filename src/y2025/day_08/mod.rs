@@ -1,7 +1,7 @@
-use crate::aoc_puzzle::AocPuzzle;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
-use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Vec3 {
@@ -212,19 +212,19 @@ fn solve_both(
     (silver_result, gold_result)
 }
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let (result, _) = solve_both(input, false, true, 1000);
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let (_, result) = solve_both(input, true, false, 1000);
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn both(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
+pub fn both(input: &str) -> PuzzleResult<(Answer, Answer)> {
     let (silver, gold) = solve_both(input, false, false, 1000);
-    (Box::new(silver), Box::new(gold))
+    Ok((Answer::from(silver), Answer::from(gold)))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -234,57 +234,18 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::{aoc_tests, read_example};
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
+    aoc_tests! {
+        2025, 8,
+        example 1 => (_, gold),
+        actual => ("63920", "1026594680"),
     }
 
-    const TEST_INPUT: &str = "162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689";
-
     #[test]
     fn silver_test_input() {
         // Test with 10 connections instead of 1000 for smaller test input
-        let (result, _) = solve_both(TEST_INPUT, false, true, 10);
+        let (result, _) = solve_both(&read_example(2025, 8, 1), false, true, 10);
         assert_eq!(result.to_string(), "40");
     }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 8);
-        assert_eq!(silver(&input).to_string(), "63920");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(TEST_INPUT).to_string(), "25272");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2025, 8);
-        assert_eq!(gold(&input).to_string(), "1026594680");
-    }
 }
@@ -1,5 +1,5 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Vec2 {
@@ -13,7 +13,7 @@ impl Vec2 {
     }
 }
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let positions: Vec<Vec2> = input
         .lines()
         .map(|line| {
@@ -35,7 +35,7 @@ pub fn silver(input: &str) -> Box<dyn Display> {
             }
         }
     }
-    Box::new(max_area)
+    Ok(Answer::from(max_area))
 }
 
 #[derive(Debug, Clone)]
@@ -45,7 +45,7 @@ struct Edge {
     index: i32,
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let positions: Vec<Vec2> = input
         .lines()
         .map(|line| {
@@ -109,7 +109,7 @@ pub fn gold(input: &str) -> Box<dyn Display> {
             }
         }
     }
-    Box::new(max_area)
+    Ok(Answer::from(max_area))
 }
 
 fn intersects(edge: &Edge, p: i32) -> bool {
@@ -157,36 +157,11 @@ pub fn puzzle() -> AocPuzzle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    const TEST_INPUT: &str = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3";
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(TEST_INPUT).to_string(), "50");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 9);
-        assert_eq!(silver(&input).to_string(), "4740155680");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(TEST_INPUT).to_string(), "24");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2025, 9);
-        assert_eq!(gold(&input).to_string(), "1543501936");
+    aoc_tests! {
+        2025, 9,
+        example 1 => (silver, gold),
+        actual => ("4740155680", "1543501936"),
     }
 }
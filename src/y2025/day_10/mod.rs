@@ -1,8 +1,8 @@
-use crate::aoc_puzzle::AocPuzzle;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 use std::collections::HashMap;
-use std::fmt::Display;
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let machines: Vec<Vec<&str>> = input.lines().map(|m| m.split(' ').collect()).collect();
     let mut result = 0;
     for machine in machines {
@@ -11,10 +11,10 @@ pub fn silver(input: &str) -> Box<dyn Display> {
             &get_buttons(&machine),
         );
     }
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let machines: Vec<Vec<&str>> = input.lines().map(|m| m.split(' ').collect()).collect();
     let mut result = 0;
     for machine in machines {
@@ -23,10 +23,10 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         let min_buttons = minimum_buttons_to_joltage_target(&target, &combinations, &mut HashMap::new());
         result += min_buttons;
     }
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn both(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
+pub fn both(input: &str) -> PuzzleResult<(Answer, Answer)> {
     let machines: Vec<Vec<&str>> = input.lines().map(|m| m.split(' ').collect()).collect();
     let mut result_silver = 0;
     let mut result_gold = 0;
@@ -52,7 +52,7 @@ pub fn both(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
         result_gold += min_buttons_to_joltage_target;
     }
 
-    (Box::new(result_silver), Box::new(result_gold))
+    Ok((Answer::from(result_silver), Answer::from(result_gold)))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -263,38 +263,11 @@ fn calculate_minimum_button_presses_recursive(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    const TEST_INPUT: &str = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
-[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
-[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(TEST_INPUT).to_string(), "7");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 10);
-        assert_eq!(silver(&input).to_string(), "486");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(TEST_INPUT).to_string(), "33");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2025, 10);
-        assert_eq!(gold(&input).to_string(), "17820");
+    aoc_tests! {
+        2025, 10,
+        example 1 => (silver, gold),
+        actual => ("486", "17820"),
     }
 }
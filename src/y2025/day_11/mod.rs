@@ -1,13 +1,13 @@
-use crate::aoc_puzzle::AocPuzzle;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
 use std::collections::HashMap;
-use std::fmt::Display;
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let devices = parse_devices(input);
-    Box::new(count_paths(&devices, "you", "out"))
+    Ok(Answer::from(count_paths(&devices, "you", "out")))
 }
 
-pub fn gold(input: &str) -> Box<dyn Display> {
+pub fn gold(input: &str) -> PuzzleResult {
     let devices = parse_devices(input);
     let mut cache = HashMap::new();
     let path_counts = count_paths_with_nodes(&devices, "svr", "out", &["fft", "dac"], &mut cache);
@@ -16,10 +16,10 @@ pub fn gold(input: &str) -> Box<dyn Display> {
         .find(|pc| pc.required_devices == 2)
         .map(|pc| pc.path_count)
         .unwrap_or(0);
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn both(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
+pub fn both(input: &str) -> PuzzleResult<(Answer, Answer)> {
     let devices = parse_devices(input);
     let silver_result = count_paths(&devices, "you", "out");
 
@@ -31,7 +31,7 @@ pub fn both(input: &str) -> (Box<dyn Display>, Box<dyn Display>) {
         .map(|pc| pc.path_count)
         .unwrap_or(0);
 
-    (Box::new(silver_result), Box::new(gold_result))
+    Ok((Answer::from(silver_result), Answer::from(gold_result)))
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -125,59 +125,12 @@ fn count_paths_with_nodes<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::aoc_tests;
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    const SILVER_TEST_INPUT: &str = "aaa: you hhh
-you: bbb ccc
-bbb: ddd eee
-ccc: ddd eee fff
-ddd: ggg
-eee: out
-fff: out
-ggg: out
-hhh: ccc fff iii
-iii: out";
-
-    const GOLD_TEST_INPUT: &str = "svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out";
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(SILVER_TEST_INPUT).to_string(), "5");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 11);
-        assert_eq!(silver(&input).to_string(), "758");
-    }
-
-    #[test]
-    fn gold_test_input() {
-        assert_eq!(gold(GOLD_TEST_INPUT).to_string(), "2");
-    }
-
-    #[test]
-    fn gold_actual_input() {
-        let input = read_input(2025, 11);
-        assert_eq!(gold(&input).to_string(), "490695961032000");
+    aoc_tests! {
+        2025, 11,
+        example 1 => (silver, _),
+        example 2 => (_, gold),
+        actual => ("758", "490695961032000"),
     }
 }
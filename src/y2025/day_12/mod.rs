@@ -1,5 +1,6 @@
-use crate::aoc_puzzle::AocPuzzle;
-use std::fmt::Display;
+use crate::answer::Answer;
+use crate::aoc_puzzle::{AocPuzzle, PuzzleResult};
+use crate::puzzle_error::PuzzleError;
 
 type Shape = Vec<Vec<bool>>;
 
@@ -10,7 +11,7 @@ struct Region {
     shape_counts: Vec<usize>,
 }
 
-pub fn silver(input: &str) -> Box<dyn Display> {
+pub fn silver(input: &str) -> PuzzleResult {
     let (shapes, regions) = parse_input(input);
     let mut result = 0;
     for region in &regions {
@@ -18,11 +19,11 @@ pub fn silver(input: &str) -> Box<dyn Display> {
             result += 1;
         }
     }
-    Box::new(result)
+    Ok(Answer::from(result))
 }
 
-pub fn gold(_input: &str) -> Box<dyn Display> {
-    Box::new("NaN")
+pub fn gold(_input: &str) -> PuzzleResult {
+    Err(PuzzleError::NotImplemented)
 }
 
 pub fn puzzle() -> AocPuzzle {
@@ -92,53 +93,24 @@ fn valid_region(region: &Region, shapes: &[Shape]) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::test_support::{actual_input, aoc_tests, read_example};
 
-    fn read_input(year: u16, day: u8) -> String {
-        fs::read_to_string(format!("./input/{}_{:02}.txt", year, day))
-            .unwrap()
-            .trim_end()
-            .to_string()
-    }
-
-    const TEST_INPUT: &str = "0:
-##.
-.##
-###
-
-1:
-..#
-###
-###
-
-2:
-#.#
-###
-#.#
-
-3x3: 1 1
-4x3: 1 1
-3x4: 1 1";
-
-    #[test]
-    fn silver_test_input() {
-        assert_eq!(silver(TEST_INPUT).to_string(), "0");
-    }
-
-    #[test]
-    fn silver_actual_input() {
-        let input = read_input(2025, 12);
-        assert_eq!(silver(&input).to_string(), "526");
+    aoc_tests! {
+        2025, 12,
+        example 1 => (silver, _),
+        actual => ("526", _),
     }
 
     #[test]
     fn gold_test_input() {
-        assert_eq!(gold(TEST_INPUT).to_string(), "NaN");
+        assert!(matches!(gold(&read_example(2025, 12, 1)), Err(PuzzleError::NotImplemented)));
     }
 
     #[test]
     fn gold_actual_input() {
-        let input = read_input(2025, 12);
-        assert_eq!(gold(&input).to_string(), "NaN");
+        let Some(input) = actual_input(2025, 12) else {
+            return;
+        };
+        assert!(matches!(gold(&input), Err(PuzzleError::NotImplemented)));
     }
 }